@@ -0,0 +1,25 @@
+#import bevy_sprite::mesh2d_vertex_output::VertexOutput
+
+const MAX_NIGHT_LIGHTS: u32 = 32u;
+
+@group(2) @binding(0) var<uniform> tint: vec4<f32>;
+// World position and radius of each light, unused ones have no radius
+@group(2) @binding(1) var<uniform> lights: array<vec4<f32>, MAX_NIGHT_LIGHTS>;
+// Color of each light, alpha is its intensity
+@group(2) @binding(2) var<uniform> colors: array<vec4<f32>, MAX_NIGHT_LIGHTS>;
+
+// Multiplied with what's already drawn, so white leaves the map untouched
+@fragment
+fn fragment(mesh: VertexOutput) -> @location(0) vec4<f32> {
+    var light = tint.rgb;
+    for (var i = 0u; i < MAX_NIGHT_LIGHTS; i++) {
+        let radius = lights[i].z;
+        if radius <= 0.0 {
+            continue;
+        }
+        let dist = distance(mesh.world_position.xy, lights[i].xy);
+        let falloff = 1.0 - smoothstep(0.0, radius, dist);
+        light += colors[i].rgb * colors[i].a * falloff * falloff;
+    }
+    return vec4(min(light, vec3(1.0)), 1.0);
+}
@@ -0,0 +1,46 @@
+#import bevy_sprite::mesh2d_vertex_output::VertexOutput
+
+@group(2) @binding(0) var<uniform> color: vec4<f32>;
+// Coverage, kind, iris center uv
+@group(2) @binding(1) var<uniform> params: vec4<f32>;
+// Aspect ratio, iris radius at no coverage, dissolve columns and rows
+@group(2) @binding(2) var<uniform> extra: vec4<f32>;
+
+const FADE: i32 = 0;
+const IRIS: i32 = 1;
+const WIPE: i32 = 2;
+const DISSOLVE: i32 = 3;
+
+fn hash(cell: vec2<f32>) -> f32 {
+    return fract(sin(dot(cell, vec2(12.9898, 78.233))) * 43758.5453);
+}
+
+@fragment
+fn fragment(mesh: VertexOutput) -> @location(0) vec4<f32> {
+    let coverage = params.x;
+    let kind = i32(params.y + 0.5);
+    let center = params.zw;
+    let aspect = extra.x;
+
+    var alpha = 0.0;
+    switch kind {
+        case IRIS: {
+            let radius = (1.0 - coverage) * extra.y;
+            let dist = length((mesh.uv - center) * vec2(aspect, 1.0));
+            alpha = select(0.0, 1.0, dist > radius);
+        }
+        case WIPE: {
+            let edge = (mesh.uv.x * aspect + mesh.uv.y) / (aspect + 1.0);
+            alpha = select(0.0, 1.0, edge < coverage);
+        }
+        case DISSOLVE: {
+            let cell = floor(mesh.uv * extra.zw);
+            alpha = select(0.0, 1.0, hash(cell) < coverage);
+        }
+        case FADE, default: {
+            alpha = coverage;
+        }
+    }
+
+    return vec4(color.rgb, color.a * alpha);
+}
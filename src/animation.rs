@@ -1,58 +1,133 @@
 use bevy::prelude::*;
+use serde::Deserialize;
+
+pub use animator::*;
+use aseprite::aseprite_plugin;
+pub use library::*;
+
+mod animator;
+mod aseprite;
+mod library;
 
 #[derive(Clone, Copy, Debug, Event, Deref, PartialEq)]
 pub struct SpriteAnimationFinished {
     pub entity: Entity,
 }
 
+// Fired when a frame with a named event is shown, e.g. "impact" on a punch frame
+#[derive(Clone, Debug, Event, PartialEq)]
+pub struct SpriteAnimationEvent {
+    pub entity: Entity,
+    pub name:   String,
+    pub frame:  usize,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Reflect, Deserialize)]
+#[reflect(Default)]
+pub enum PlaybackMode {
+    #[default]
+    Forward,
+    Reverse,
+    // First to last and back to first
+    PingPong,
+    // Last to first and back to last
+    PingPongReverse,
+}
+
 #[derive(Debug, Component, Reflect)]
 #[reflect(Component)]
 pub struct SpriteAnimation {
     first_index: usize,
     last_index:  usize,
+    frame:       usize,
 
     frame_timer: Timer,
+    fps:         f32,
+    // Seconds per frame starting from first_index, frames past the end use fps
+    durations:   Vec<f32>,
     delay:       f32,
 
+    mode:      PlaybackMode,
+    reversing: bool,
+    // Atlas index and name of the events fired when that frame is shown
+    events:    Vec<(usize, String)>,
+
     looping: bool,
     playing: bool,
     started: bool,
+    // Finishes as soon as it's shown instead of waiting on frame_timer
+    instant: bool,
+    jump:    Option<usize>,
 }
 
 impl SpriteAnimation {
     pub fn new(first: usize, last: usize, fps: u8) -> Self {
+        SpriteAnimation::new_fractional(first, last, fps as f32)
+    }
+
+    pub fn new_fractional(first: usize, last: usize, fps: f32) -> Self {
         SpriteAnimation {
             first_index: first,
-            last_index:  last,
-            frame_timer: Timer::from_seconds((fps as f32).recip(), TimerMode::Once),
-            delay:       0.0, // because I need it and don't want another to add another Timer :p
-            looping:     false,
-            playing:     true,
-            started:     false,
+            last_index: last,
+            frame: first,
+            frame_timer: Timer::from_seconds(fps.recip(), TimerMode::Once),
+            fps,
+            durations: Vec::new(),
+            delay: 0.0, // because I need it and don't want another to add another Timer :p
+            mode: PlaybackMode::Forward,
+            reversing: false,
+            events: Vec::new(),
+            looping: false,
+            playing: true,
+            started: false,
+            instant: false,
+            jump: None,
         }
     }
 
-    // A little hack to change sprite without the Sprite component
-    // Will set the frame on the NEXT render update
+    // Changes sprite without the Sprite component, the frame is shown and the
+    // animation finishes on the next update no matter the frame rate
     pub fn set_frame(index: usize) -> Self {
         SpriteAnimation {
-            first_index: index,
-            last_index:  index,
-            // Sadly this might cause inconsistencies with systems running
-            // at over 1000 frames per second. Apologies to those users.
-            frame_timer: Timer::from_seconds(0.001, TimerMode::Once),
-            delay:       0.0,
-            looping:     false,
-            playing:     true,
-            started:     false,
+            instant: true,
+            ..SpriteAnimation::new_fractional(index, index, f32::INFINITY)
         }
     }
 
+    // Shows the frame on this update, keeping the animation's range and timing
+    pub fn go_to_frame(&mut self, frame: usize) {
+        self.jump = Some(frame.clamp(self.first_index, self.last_index));
+    }
+
     pub fn with_delay(mut self, secs: f32) -> Self {
         self.delay = secs;
         self
     }
 
+    pub fn with_mode(mut self, mode: PlaybackMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    pub fn reversed(self) -> Self {
+        self.with_mode(PlaybackMode::Reverse)
+    }
+
+    pub fn ping_pong(self) -> Self {
+        self.with_mode(PlaybackMode::PingPong)
+    }
+
+    // Seconds each frame is shown for, starting from the first frame
+    pub fn with_durations(mut self, durations: impl Into<Vec<f32>>) -> Self {
+        self.durations = durations.into();
+        self
+    }
+
+    pub fn with_event(mut self, frame: usize, name: impl Into<String>) -> Self {
+        self.events.push((frame, name.into()));
+        self
+    }
+
     pub fn looping(mut self) -> Self {
         self.looping = true;
         self
@@ -71,74 +146,226 @@ impl SpriteAnimation {
         self.playing = false;
     }
 
-    pub fn change_fps(&mut self, fps: u8) {
+    // Also drops per-frame durations, every frame plays at the new fps
+    pub fn change_fps(&mut self, fps: f32) {
+        self.fps = fps;
+        self.durations.clear();
         let elapsed = self.frame_timer.elapsed();
-        self.frame_timer = Timer::from_seconds((fps as f32).recip(), TimerMode::Once);
+        self.frame_timer = Timer::from_seconds(self.frame_duration(self.frame), TimerMode::Once);
         self.frame_timer.set_elapsed(elapsed);
     }
+
+    fn frame_duration(&self, frame: usize) -> f32 {
+        self.durations
+            .get(frame.saturating_sub(self.first_index))
+            .copied()
+            .unwrap_or(self.fps.recip())
+    }
+
+    fn start_frame(&self) -> usize {
+        match self.mode {
+            PlaybackMode::Reverse | PlaybackMode::PingPongReverse => self.last_index,
+            _ => self.first_index,
+        }
+    }
+
+    // None when the current frame is the end of the animation
+    fn next_frame(&mut self) -> Option<usize> {
+        let forward = self.frame < self.last_index;
+        let backward = self.frame > self.first_index;
+        match (self.mode, self.reversing) {
+            (PlaybackMode::Forward, _)
+            | (PlaybackMode::PingPong, false)
+            | (PlaybackMode::PingPongReverse, true)
+                if forward =>
+            {
+                Some(self.frame + 1)
+            }
+            (PlaybackMode::PingPong, false) if backward => {
+                self.reversing = true;
+                Some(self.frame - 1)
+            }
+            (PlaybackMode::Reverse, _)
+            | (PlaybackMode::PingPong, true)
+            | (PlaybackMode::PingPongReverse, false)
+                if backward =>
+            {
+                Some(self.frame - 1)
+            }
+            (PlaybackMode::PingPongReverse, false) if forward => {
+                self.reversing = true;
+                Some(self.frame + 1)
+            }
+            _ => None,
+        }
+    }
+
+    fn restart(&mut self) -> usize {
+        self.reversing = false;
+        self.frame = self.start_frame();
+        match self.mode {
+            // Ping-pong ends on its start frame, don't show it twice
+            PlaybackMode::PingPong | PlaybackMode::PingPongReverse => {
+                self.next_frame().unwrap_or(self.frame)
+            }
+            _ => self.frame,
+        }
+    }
+
+    fn show_frame(&mut self, frame: usize) {
+        self.frame = frame;
+        self.frame_timer = Timer::from_seconds(self.frame_duration(frame), TimerMode::Once);
+    }
+
+    fn frame_events(&self, entity: Entity) -> impl Iterator<Item = SpriteAnimationEvent> {
+        let frame = self.frame;
+        self.events
+            .iter()
+            .filter(move |(event_frame, _)| *event_frame == frame)
+            .map(move |(_, name)| SpriteAnimationEvent {
+                entity,
+                name: name.clone(),
+                frame,
+            })
+    }
 }
 
+// Separate images played as frames instead of a texture atlas, frame indices
+// index into the sequence
+#[derive(Debug, Clone, Component, Deref)]
+pub struct SpriteImages(pub Vec<Handle<Image>>);
+
 pub fn sprite_animations_plugin(app: &mut App) {
-    app.add_systems(Update, (set_first_frame, play_animations).chain())
-        .register_type::<SpriteAnimation>()
-        .add_event::<SpriteAnimationFinished>();
+    app.add_plugins((
+        sprite_animation_library_plugin,
+        sprite_animator_plugin,
+        aseprite_plugin,
+    ))
+    .add_systems(Update, (set_first_frame, play_animations).chain())
+    .register_type::<SpriteAnimation>()
+    .register_type::<PlaybackMode>()
+    .add_event::<SpriteAnimationFinished>()
+    .add_event::<SpriteAnimationEvent>();
 }
 
-fn set_first_frame(mut query: Query<(&mut SpriteAnimation, &mut Sprite)>) {
-    query.iter_mut().for_each(|(mut animation, mut sprite)| {
-        if !animation.started {
-            sprite
-                .texture_atlas
-                .as_mut()
-                .expect("Animated Sprite with no Texture Atlas")
-                .index = animation.first_index;
-            animation.started = true;
+type AnimatedSprite<'a> = (
+    Entity,
+    &'a mut SpriteAnimation,
+    &'a mut Sprite,
+    Option<&'a SpriteImages>,
+);
+
+// Shows the frame on the sprite's image sequence or atlas, false if it has neither
+fn apply_frame(sprite: &mut Sprite, images: Option<&SpriteImages>, frame: usize) -> bool {
+    match (images, sprite.texture_atlas.as_mut()) {
+        (Some(images), _) => match images.get(frame) {
+            Some(image) => {
+                sprite.image = image.clone();
+                true
+            }
+            None => false,
+        },
+        (None, Some(atlas)) => {
+            atlas.index = frame;
+            true
         }
-    });
+        (None, None) => false,
+    }
+}
+
+fn set_first_frame(
+    mut commands: Commands,
+    mut e_writer: EventWriter<SpriteAnimationEvent>,
+    mut query: Query<AnimatedSprite>,
+) {
+    query
+        .iter_mut()
+        .for_each(|(entity, mut animation, mut sprite, images)| {
+            let frame = match (animation.started, animation.jump.take()) {
+                (_, Some(frame)) => frame,
+                (false, None) => {
+                    animation.reversing = false;
+                    animation.start_frame()
+                }
+                (true, None) => return,
+            };
+
+            animation.show_frame(frame);
+            animation.started = true;
+
+            if !apply_frame(&mut sprite, images, frame) {
+                warn!(
+                    "{entity}: no TextureAtlas or SpriteImages frame {frame} to animate, pausing"
+                );
+                animation.pause();
+                return;
+            }
+
+            animation.frame_events(entity).for_each(|event| {
+                e_writer.write(event.clone());
+                commands.trigger_targets(event, entity);
+            });
+        });
 }
 
 fn play_animations(
     mut commands: Commands,
-    mut e_writer: EventWriter<SpriteAnimationFinished>,
-    mut query: Query<(Entity, &mut SpriteAnimation, &mut Sprite)>,
+    mut e_finished: EventWriter<SpriteAnimationFinished>,
+    mut e_frame: EventWriter<SpriteAnimationEvent>,
+    mut query: Query<AnimatedSprite>,
     time: Res<Time>,
 ) {
     query
         .iter_mut()
-        .filter(|(_entity, animation, _sprite)| animation.playing)
-        .for_each(|(entity, mut animation, mut sprite)| {
+        .filter(|(_entity, animation, ..)| animation.playing)
+        .for_each(|(entity, mut animation, mut sprite, images)| {
             if animation.delay > 0.0 {
                 animation.delay -= time.delta_secs();
             }
 
-            // delay check will short-circuit the if statement and not tick the frame_timer... I hope
-            if animation.delay <= 0.0 && animation.frame_timer.tick(time.delta()).just_finished() {
-                let atlas = sprite
-                    .texture_atlas
-                    .as_mut()
-                    .expect("Animated Sprite with no Texture Atlas");
-
-                // let previous_index = atlas.index;
-                if atlas.index == animation.last_index {
-                    if animation.looping {
-                        atlas.index = animation.first_index;
-                        animation.frame_timer.reset();
-                    }
+            if animation.delay > 0.0 {
+                return;
+            }
+
+            let frame_finished = match animation.instant {
+                true => {
+                    animation.instant = false;
+                    true
+                }
+                false => animation.frame_timer.tick(time.delta()).just_finished(),
+            };
+
+            if !frame_finished {
+                return;
+            }
+
+            let next_frame = match animation.next_frame() {
+                Some(frame) => Some(frame),
+                None => {
                     let event = SpriteAnimationFinished { entity };
-                    e_writer.write(event);
+                    e_finished.write(event);
                     commands.trigger_targets(event, entity);
-                } else if atlas.index < animation.last_index {
-                    atlas.index += 1;
-                    animation.frame_timer.reset();
+                    animation.looping.then(|| animation.restart())
                 }
-                // info!(
-                //     "Playing sprite index {} - {}",
-                //     animation.first_index, animation.last_index
-                // );
-                // info!(
-                //     "Previous index: {} Current index: {}",
-                //     previous_index, atlas.index
-                // );
+            };
+
+            let Some(frame) = next_frame else {
+                return;
+            };
+
+            animation.show_frame(frame);
+
+            if !apply_frame(&mut sprite, images, frame) {
+                warn!(
+                    "{entity}: no TextureAtlas or SpriteImages frame {frame} to animate, pausing"
+                );
+                animation.pause();
+                return;
             }
+
+            animation.frame_events(entity).for_each(|event| {
+                e_frame.write(event.clone());
+                commands.trigger_targets(event, entity);
+            });
         });
 }
@@ -0,0 +1,264 @@
+use bevy::{platform::collections::HashMap, prelude::*};
+
+use super::*;
+
+pub fn sprite_animator_plugin(app: &mut App) {
+    app.add_systems(Update, update_sprite_animators.after(play_animations));
+}
+
+// Triggered on the entity whenever its SpriteAnimator plays a clip, either
+// because it entered a new state or its variant or facing changed
+#[derive(Debug, Clone, Event)]
+pub struct AnimatorClipChanged {
+    pub entity:   Entity,
+    pub previous: &'static str,
+    pub state:    &'static str,
+    pub clip:     String,
+}
+
+impl AnimatorClipChanged {
+    pub fn entered(&self, state: &str) -> bool {
+        self.state == state && self.previous != state
+    }
+
+    pub fn exited(&self, state: &str) -> bool {
+        self.previous == state && self.state != state
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AnimatorParam {
+    Bool(bool),
+    Float(f32),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AnimatorCondition {
+    // The current clip played to its last frame
+    Finished,
+    Is(&'static str),
+    Not(&'static str),
+    Above(&'static str, f32),
+    Below(&'static str, f32),
+}
+
+#[derive(Debug, Clone)]
+pub struct AnimatorState {
+    clip:        &'static str,
+    directional: bool,
+    turning:     bool,
+    transitions: Vec<(&'static str, Vec<AnimatorCondition>)>,
+}
+
+impl AnimatorState {
+    pub fn new(clip: &'static str) -> Self {
+        AnimatorState {
+            clip,
+            directional: false,
+            turning: false,
+            transitions: Vec::new(),
+        }
+    }
+
+    // Plays the "_east" or "_west" variant of the clip for the facing when the
+    // state is entered
+    pub fn directional(mut self) -> Self {
+        self.directional = true;
+        self
+    }
+
+    // Like directional, but also replays the clip when the facing changes
+    pub fn turning(mut self) -> Self {
+        self.directional = true;
+        self.turning = true;
+        self
+    }
+
+    // Goes to the state once all conditions hold, the first transition that matches wins
+    pub fn to(
+        mut self,
+        state: &'static str,
+        conditions: impl Into<Vec<AnimatorCondition>>,
+    ) -> Self {
+        self.transitions.push((state, conditions.into()));
+        self
+    }
+}
+
+// Plays clips from the entity's SpriteAnimations by state, taking at most one
+// transition per frame
+#[derive(Debug, Component)]
+pub struct SpriteAnimator {
+    states:          HashMap<&'static str, AnimatorState>,
+    any_transitions: Vec<(&'static str, Vec<AnimatorCondition>)>,
+    params:          HashMap<&'static str, AnimatorParam>,
+    current:         &'static str,
+    facing:          &'static str,
+    variant:         Option<String>,
+    finished:        bool,
+    dirty:           bool,
+}
+
+impl SpriteAnimator {
+    pub fn new(initial: &'static str) -> Self {
+        SpriteAnimator {
+            states:          HashMap::default(),
+            any_transitions: Vec::new(),
+            params:          HashMap::default(),
+            current:         initial,
+            facing:          "east",
+            variant:         None,
+            finished:        false,
+            dirty:           true,
+        }
+    }
+
+    pub fn with_state(mut self, name: &'static str, state: AnimatorState) -> Self {
+        self.states.insert(name, state);
+        self
+    }
+
+    // Checked before the current state's transitions, from every other state
+    pub fn with_any_transition(
+        mut self,
+        state: &'static str,
+        conditions: impl Into<Vec<AnimatorCondition>>,
+    ) -> Self {
+        self.any_transitions.push((state, conditions.into()));
+        self
+    }
+
+    pub fn set_bool(&mut self, name: &'static str, value: bool) {
+        self.params.insert(name, AnimatorParam::Bool(value));
+    }
+
+    pub fn set_float(&mut self, name: &'static str, value: f32) {
+        self.params.insert(name, AnimatorParam::Float(value));
+    }
+
+    // Moving straight up or down keeps the facing
+    pub fn set_direction(&mut self, direction: Dir2) {
+        let facing = match direction.x {
+            x if x > 0.0 => "east",
+            x if x < 0.0 => "west",
+            _ => self.facing,
+        };
+        if facing != self.facing {
+            self.facing = facing;
+            self.dirty |= self
+                .states
+                .get(self.current)
+                .is_some_and(|state| state.turning);
+        }
+    }
+
+    // Plays the current clip again, e.g. once its set is reloaded
+    pub fn replay(&mut self) {
+        self.dirty = true;
+    }
+
+    // Appended to the clip name before the facing, e.g. "run" becomes "run_3"
+    pub fn set_variant(&mut self, variant: impl ToString) {
+        let variant = Some(variant.to_string());
+        if variant != self.variant {
+            self.variant = variant;
+            self.dirty = true;
+        }
+    }
+
+    fn holds(&self, condition: &AnimatorCondition) -> bool {
+        let param = |name: &str| self.params.get(name).copied();
+        match *condition {
+            AnimatorCondition::Finished => self.finished,
+            AnimatorCondition::Is(name) => param(name) == Some(AnimatorParam::Bool(true)),
+            AnimatorCondition::Not(name) => param(name) != Some(AnimatorParam::Bool(true)),
+            AnimatorCondition::Above(name, value) => {
+                matches!(param(name), Some(AnimatorParam::Float(param)) if param > value)
+            }
+            AnimatorCondition::Below(name, value) => {
+                matches!(param(name), Some(AnimatorParam::Float(param)) if param < value)
+            }
+        }
+    }
+
+    fn next_state(&self) -> Option<&'static str> {
+        let state_transitions = self
+            .states
+            .get(self.current)
+            .map(|state| state.transitions.as_slice())
+            .unwrap_or_default();
+
+        self.any_transitions
+            .iter()
+            .chain(state_transitions)
+            .filter(|(state, _)| *state != self.current)
+            .find(|(_, conditions)| conditions.iter().all(|condition| self.holds(condition)))
+            .map(|(state, _)| *state)
+    }
+
+    fn clip(&self) -> String {
+        let Some(state) = self.states.get(self.current) else {
+            warn!("SpriteAnimator has no state \"{}\"", self.current);
+            return String::new();
+        };
+
+        let mut clip = state.clip.to_string();
+        if let Some(variant) = &self.variant {
+            clip = format!("{clip}_{variant}");
+        }
+        if state.directional {
+            clip = format!("{clip}_{}", self.facing);
+        }
+        clip
+    }
+}
+
+fn update_sprite_animators(
+    mut commands: Commands,
+    mut e_finished: EventReader<SpriteAnimationFinished>,
+    mut q_animators: Query<(
+        Entity,
+        &mut SpriteAnimator,
+        &SpriteAnimations,
+        &mut SpriteAnimation,
+    )>,
+    sets: Res<Assets<SpriteAnimationSet>>,
+) {
+    e_finished.read().for_each(|event| {
+        if let Ok((_, mut animator, ..)) = q_animators.get_mut(event.entity) {
+            animator.finished = true;
+        }
+    });
+
+    q_animators
+        .iter_mut()
+        .for_each(|(entity, mut animator, animations, mut animation)| {
+            if !sets.contains(animations.id()) {
+                return;
+            }
+
+            let previous = animator.current;
+
+            if let Some(next) = animator.next_state() {
+                animator.current = next;
+                animator.dirty = true;
+            }
+
+            if !animator.dirty {
+                return;
+            }
+
+            let clip = animator.clip();
+            *animation = animations.clip(&sets, &clip);
+            animator.dirty = false;
+            animator.finished = false;
+
+            let event = AnimatorClipChanged {
+                entity,
+                previous,
+                state: animator.current,
+                clip,
+            };
+            commands.trigger_targets(event, entity);
+        });
+}
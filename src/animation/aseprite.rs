@@ -0,0 +1,176 @@
+use bevy::{
+    asset::{AssetLoader, LoadContext, io::Reader},
+    platform::collections::HashMap,
+    prelude::*,
+};
+use serde::Deserialize;
+use thiserror::Error;
+
+use super::*;
+
+pub fn aseprite_plugin(app: &mut App) {
+    app.init_asset_loader::<AsepriteLoader>();
+}
+
+// Aseprite's JSON data export, frames must be exported as an Array
+#[derive(Debug, Deserialize)]
+struct AsepriteFile {
+    frames: Vec<AsepriteFrame>,
+    meta:   AsepriteMeta,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct AsepriteFrame {
+    frame:    AsepriteRect,
+    #[serde(default)]
+    rotated:  bool,
+    #[serde(default)]
+    trimmed:  bool,
+    // Milliseconds
+    duration: u32,
+}
+
+#[derive(Debug, Deserialize)]
+struct AsepriteRect {
+    x: u32,
+    y: u32,
+    w: u32,
+    h: u32,
+}
+
+#[derive(Debug, Deserialize)]
+struct AsepriteSize {
+    w: u32,
+    h: u32,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct AsepriteMeta {
+    // Relative to the JSON file
+    image:      String,
+    size:       AsepriteSize,
+    #[serde(default)]
+    frame_tags: Vec<AsepriteTag>,
+}
+
+#[derive(Debug, Deserialize)]
+struct AsepriteTag {
+    name:      String,
+    from:      usize,
+    to:        usize,
+    #[serde(default)]
+    direction: String,
+    // Missing or "0" loops forever, repeat counts aren't supported and play once
+    #[serde(default)]
+    repeat:    Option<String>,
+}
+
+impl AsepriteTag {
+    fn clip(&self, frames: &[AsepriteFrame]) -> SpriteClip {
+        let durations: Vec<f32> = frames
+            .get(self.from..=self.to)
+            .unwrap_or_default()
+            .iter()
+            .map(|frame| frame.duration as f32 / 1000.0)
+            .collect();
+        let fps = durations.first().map_or(12.0, |duration| duration.recip());
+
+        SpriteClip {
+            first: self.from,
+            last: self.to,
+            fps,
+            looping: self.repeat.as_deref().is_none_or(|repeat| repeat == "0"),
+            mode: match self.direction.as_str() {
+                "reverse" => PlaybackMode::Reverse,
+                "pingpong" => PlaybackMode::PingPong,
+                "pingpong_reverse" => PlaybackMode::PingPongReverse,
+                _ => PlaybackMode::Forward,
+            },
+            durations,
+            events: Vec::new(),
+        }
+    }
+}
+
+#[derive(Default)]
+struct AsepriteLoader;
+
+/// Possible errors that can be produced by [`AsepriteLoader`]
+#[non_exhaustive]
+#[derive(Debug, Error)]
+enum AsepriteLoaderError {
+    /// An [IO](std::io) Error
+    #[error("Could not load file: {0}")]
+    Io(#[from] std::io::Error),
+    /// A [JSON](serde_json) Error
+    #[error("Could not parse Aseprite data: {0}")]
+    Json(#[from] serde_json::Error),
+}
+
+impl AssetLoader for AsepriteLoader {
+    type Asset = SpriteAnimationSet;
+    type Settings = ();
+    type Error = AsepriteLoaderError;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &(),
+        load_context: &mut LoadContext<'_>,
+    ) -> Result<Self::Asset, Self::Error> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+        let file: AsepriteFile = serde_json::from_slice(&bytes)?;
+
+        let path = load_context.path().to_owned();
+        let size = uvec2(file.meta.size.w, file.meta.size.h);
+        let mut layout = TextureAtlasLayout::new_empty(size);
+        file.frames.iter().for_each(|frame| {
+            let AsepriteRect { x, y, w, h } = frame.frame;
+            layout.add_texture(URect::new(x, y, x + w, y + h));
+        });
+
+        if file
+            .frames
+            .iter()
+            .any(|frame| frame.rotated || frame.trimmed)
+        {
+            warn!("{path:?}: rotated or trimmed frames aren't supported, export without them");
+        }
+
+        file.meta
+            .frame_tags
+            .iter()
+            .filter(|tag| tag.repeat.as_deref().is_some_and(|repeat| repeat != "0"))
+            .for_each(|tag| {
+                warn!(
+                    "{path:?}: tag \"{}\" repeats a set number of times, it will play once",
+                    tag.name
+                );
+            });
+
+        let clips: HashMap<String, SpriteClip> = file
+            .meta
+            .frame_tags
+            .iter()
+            .map(|tag| (tag.name.clone(), tag.clip(&file.frames)))
+            .collect();
+
+        let image_path = path.parent().map_or_else(
+            || file.meta.image.clone().into(),
+            |dir| dir.join(&file.meta.image),
+        );
+
+        Ok(SpriteAnimationSet::new(
+            load_context.load(image_path),
+            load_context.add_labeled_asset("layout".to_string(), layout),
+            clips,
+        ))
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["aseprite.json"]
+    }
+}
@@ -0,0 +1,252 @@
+use bevy::{
+    asset::{AssetLoader, LoadContext, io::Reader},
+    platform::collections::HashMap,
+    prelude::*,
+};
+use serde::Deserialize;
+use thiserror::Error;
+
+use super::*;
+
+pub fn sprite_animation_library_plugin(app: &mut App) {
+    app.add_systems(Update, apply_sprite_animation_sets.before(set_first_frame))
+        .init_asset::<SpriteAnimationSet>()
+        .init_asset_loader::<SpriteAnimationSetLoader>();
+}
+
+// A named range of frames on a sprite sheet
+#[derive(Debug, Clone, Deserialize)]
+pub struct SpriteClip {
+    pub first:     usize,
+    pub last:      usize,
+    pub fps:       f32,
+    #[serde(default)]
+    pub looping:   bool,
+    #[serde(default)]
+    pub mode:      PlaybackMode,
+    // Seconds per frame starting from first, overrides fps
+    #[serde(default)]
+    pub durations: Vec<f32>,
+    // Atlas index and name of events fired on that frame, e.g. (6, "land")
+    #[serde(default)]
+    pub events:    Vec<(usize, String)>,
+}
+
+impl SpriteClip {
+    // Reason the clip can't be played, timers panic on negative or infinite durations
+    fn invalid(&self) -> Option<&'static str> {
+        if self.fps <= 0.0 || !self.fps.is_finite() {
+            return Some("needs an fps above 0");
+        }
+        if self
+            .durations
+            .iter()
+            .any(|duration| *duration < 0.0 || !duration.is_finite())
+        {
+            return Some("has a negative frame duration");
+        }
+        None
+    }
+
+    // Single frame clips finish right away like SpriteAnimation::set_frame
+    pub fn animation(&self) -> SpriteAnimation {
+        let animation = match self.first == self.last && self.durations.is_empty() {
+            true => SpriteAnimation::set_frame(self.first),
+            false => SpriteAnimation::new_fractional(self.first, self.last, self.fps)
+                .with_mode(self.mode)
+                .with_durations(self.durations.clone()),
+        };
+        let animation = self
+            .events
+            .iter()
+            .fold(animation, |animation, (frame, name)| {
+                animation.with_event(*frame, name.clone())
+            });
+        match self.looping {
+            true => animation.looping(),
+            false => animation,
+        }
+    }
+}
+
+// A sprite sheet and its clips, loaded from a .anim.ron or .aseprite.json file
+#[derive(Debug, Asset, TypePath)]
+pub struct SpriteAnimationSet {
+    pub image:  Handle<Image>,
+    pub layout: Handle<TextureAtlasLayout>,
+    clips:      HashMap<String, SpriteClip>,
+}
+
+impl SpriteAnimationSet {
+    pub(super) fn new(
+        image: Handle<Image>,
+        layout: Handle<TextureAtlasLayout>,
+        clips: HashMap<String, SpriteClip>,
+    ) -> Self {
+        SpriteAnimationSet {
+            image,
+            layout,
+            clips,
+        }
+    }
+
+    pub fn get(&self, name: &str) -> Option<&SpriteClip> {
+        self.clips.get(name)
+    }
+
+    // Animation of the named clip, a missing clip holds the first frame
+    pub fn clip(&self, name: &str) -> SpriteAnimation {
+        self.get(name)
+            .map(|clip| clip.animation())
+            .unwrap_or_else(|| {
+                warn!("Sprite animation clip \"{name}\" missing");
+                SpriteAnimation::set_frame(0)
+            })
+    }
+}
+
+// Handle to the SpriteAnimationSet an entity plays its clips from
+#[derive(Debug, Clone, Component, Deref)]
+pub struct SpriteAnimations(pub Handle<SpriteAnimationSet>);
+
+impl SpriteAnimations {
+    // Sprite already has the set's layout so it can be animated while the image
+    // is loading, the image is applied once the set is loaded
+    pub fn load(asset_server: &AssetServer, path: &str) -> (Self, Sprite) {
+        let layout = asset_server.load(format!("{path}#layout"));
+        let sprite = Sprite {
+            texture_atlas: Some(TextureAtlas { layout, index: 0 }),
+            ..default()
+        };
+        (SpriteAnimations(asset_server.load(path)), sprite)
+    }
+
+    pub fn clip(&self, sets: &Assets<SpriteAnimationSet>, name: &str) -> SpriteAnimation {
+        match sets.get(&self.0) {
+            Some(set) => set.clip(name),
+            None => {
+                warn!("Sprite animation clip \"{name}\" played before its set loaded");
+                SpriteAnimation::set_frame(0)
+            }
+        }
+    }
+}
+
+// Keeps sprites in sync with their set, including when it's reloaded
+fn apply_sprite_animation_sets(
+    mut e_asset: EventReader<AssetEvent<SpriteAnimationSet>>,
+    mut q_sprites: Query<(
+        Ref<SpriteAnimations>,
+        &mut Sprite,
+        Option<&mut SpriteAnimator>,
+    )>,
+    sets: Res<Assets<SpriteAnimationSet>>,
+) {
+    let mut changed_sets = Vec::new();
+    let mut reloaded_sets = Vec::new();
+    e_asset.read().for_each(|event| match event {
+        AssetEvent::LoadedWithDependencies { id } => changed_sets.push(*id),
+        AssetEvent::Modified { id } => {
+            changed_sets.push(*id);
+            reloaded_sets.push(*id);
+        }
+        _ => (),
+    });
+
+    q_sprites
+        .iter_mut()
+        .filter(|(animations, ..)| animations.is_added() || changed_sets.contains(&animations.id()))
+        .for_each(|(animations, mut sprite, animator)| {
+            let Some(set) = sets.get(&animations.0) else {
+                return;
+            };
+            sprite.image = set.image.clone();
+            if let Some(atlas) = sprite.texture_atlas.as_mut() {
+                atlas.layout = set.layout.clone();
+            }
+            // The playing clip may have new frames or timings
+            if let Some(mut animator) =
+                animator.filter(|_| reloaded_sets.contains(&animations.id()))
+            {
+                animator.replay();
+            }
+        });
+}
+
+#[derive(Debug, Deserialize)]
+struct SpriteAnimationSetFile {
+    // Relative to the assets folder
+    image:     String,
+    tile_size: UVec2,
+    columns:   u32,
+    rows:      u32,
+    #[serde(default)]
+    padding:   Option<UVec2>,
+    #[serde(default)]
+    offset:    Option<UVec2>,
+    clips:     HashMap<String, SpriteClip>,
+}
+
+#[derive(Default)]
+struct SpriteAnimationSetLoader;
+
+/// Possible errors that can be produced by [`SpriteAnimationSetLoader`]
+#[non_exhaustive]
+#[derive(Debug, Error)]
+enum SpriteAnimationSetLoaderError {
+    /// An [IO](std::io) Error
+    #[error("Could not load file: {0}")]
+    Io(#[from] std::io::Error),
+    /// A [RON](ron) Error
+    #[error("Could not parse animation set: {0}")]
+    Ron(#[from] bevy::scene::ron::de::SpannedError),
+    /// A clip that would panic when played
+    #[error("Clip \"{0}\" {1}")]
+    InvalidClip(String, &'static str),
+}
+
+impl AssetLoader for SpriteAnimationSetLoader {
+    type Asset = SpriteAnimationSet;
+    type Settings = ();
+    type Error = SpriteAnimationSetLoaderError;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &(),
+        load_context: &mut LoadContext<'_>,
+    ) -> Result<Self::Asset, Self::Error> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+        let file: SpriteAnimationSetFile = bevy::scene::ron::de::from_bytes(&bytes)?;
+
+        if let Some((name, reason)) = file
+            .clips
+            .iter()
+            .find_map(|(name, clip)| Some((name, clip.invalid()?)))
+        {
+            return Err(SpriteAnimationSetLoaderError::InvalidClip(
+                name.clone(),
+                reason,
+            ));
+        }
+
+        let layout = TextureAtlasLayout::from_grid(
+            file.tile_size,
+            file.columns,
+            file.rows,
+            file.padding,
+            file.offset,
+        );
+
+        Ok(SpriteAnimationSet::new(
+            load_context.load(file.image),
+            load_context.add_labeled_asset("layout".to_string(), layout),
+            file.clips,
+        ))
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["anim.ron"]
+    }
+}
@@ -7,24 +7,23 @@ pub fn audio_plugin(app: &mut App) {
     app.add_systems(
         Update,
         (
-            fade_in::<AudioSink>,
-            fade_in::<SpatialAudioSink>,
-            fade_out::<AudioSink>,
-            fade_out::<SpatialAudioSink>,
-        ),
-    );
+            (fade_in, fade_out, update_music_duck),
+            (
+                update_bus_volumes::<Sound, AudioSink>,
+                update_bus_volumes::<Sound, SpatialAudioSink>,
+                update_bus_volumes::<Music, AudioSink>,
+                update_bus_volumes::<Music, SpatialAudioSink>,
+                update_bus_volumes::<Ambience, AudioSink>,
+                update_bus_volumes::<Ambience, SpatialAudioSink>,
+                update_bus_volumes::<Voice, AudioSink>,
+                update_bus_volumes::<Voice, SpatialAudioSink>,
+            ),
+        )
+            .chain(),
+    )
+    .init_resource::<MusicDuck>();
 }
 
-// fn set_added_audio_volume(
-//     settings: Res<Persistent<Settings>>,
-//     mut q_sinks: Query<&mut AudioSink, (Added<AudioSink>, Without<AudioFadeIn>)>,
-// ) {
-//     let volume_music = Volume::Linear(settings.music_vol);
-//     q_sinks
-//         .iter_mut()
-//         .for_each(|mut sink| sink.set_volume(volume_music));
-// }
-
 #[derive(Debug, Component)]
 pub struct Sound;
 
@@ -34,76 +33,171 @@ pub struct Music;
 #[derive(Debug, Component)]
 pub struct Ambience;
 
-pub trait AudioType {}
+#[derive(Debug, Component)]
+pub struct Voice;
+
+// A mixer bus, every playing sink is scaled by the master volume and its bus volume
+pub trait AudioType: Component {
+    const DUCKED: bool = false;
+
+    fn bus_volume(settings: &Settings) -> f32;
+
+    // Volume to spawn a sink of this bus with, before the mixer takes over
+    fn volume(settings: &Settings) -> Volume {
+        Volume::Linear(settings.master_vol * Self::bus_volume(settings))
+    }
+}
+
+impl AudioType for Sound {
+    fn bus_volume(settings: &Settings) -> f32 {
+        settings.sound_vol
+    }
+}
 
-impl AudioType for Sound {}
+impl AudioType for Music {
+    const DUCKED: bool = true;
 
-impl AudioType for Music {}
+    fn bus_volume(settings: &Settings) -> f32 {
+        settings.music_vol
+    }
+}
+
+impl AudioType for Ambience {
+    fn bus_volume(settings: &Settings) -> f32 {
+        settings.ambience_vol
+    }
+}
 
-impl AudioType for Ambience {}
+impl AudioType for Voice {
+    fn bus_volume(settings: &Settings) -> f32 {
+        settings.voice_vol
+    }
+}
 
 const FADE_TIME: f32 = 2.0;
 
 #[derive(Debug, Component)]
+#[require(AudioFade = AudioFade(0.0))]
 pub struct AudioFadeIn;
 
 #[derive(Debug, Component)]
+#[require(AudioFade = AudioFade(1.0))]
 pub struct AudioFadeOut;
 
-use bevy::ecs::component::Mutable;
+// How far faded in a sink is, from 0 (silent) to 1
+#[derive(Debug, Clone, Copy, Component)]
+pub struct AudioFade(f32);
+
+// Volume of a single sink relative to its bus
+#[derive(Debug, Clone, Copy, Component)]
+pub struct AudioGain(pub f32);
+
+// Music that plays at full volume while the rest of the music is ducked
+#[derive(Debug, Component)]
+pub struct Unducked;
+
+// Lowers Music while active, e.g. during dialogue
+#[derive(Debug, Default, Resource)]
+pub struct MusicDuck {
+    pub active: bool,
+    level:      f32,
+}
+
+impl MusicDuck {
+    const DUCKED_VOLUME: f32 = 0.35;
+    const DUCK_TIME: f32 = 0.5;
+
+    fn factor(&self) -> f32 {
+        1.0 - (1.0 - MusicDuck::DUCKED_VOLUME) * self.level
+    }
+}
 
-fn fade_in<S>(
+fn fade_in(
     mut commands: Commands,
-    mut q_audio_sink: Query<(Entity, &mut S), With<AudioFadeIn>>,
-    settings: Res<Persistent<Settings>>,
+    mut q_fades: Query<(Entity, &mut AudioFade), With<AudioFadeIn>>,
     time: Res<Time>,
-) where
-    S: Component<Mutability = Mutable> + AudioSinkPlayback,
-{
-    q_audio_sink.iter_mut().for_each(|(entity, mut sink)| {
-        let current_volume = sink.volume();
-        let new_volume =
-            current_volume + Volume::Linear((time.delta_secs() / FADE_TIME) * settings.music_vol);
-        sink.set_volume(new_volume);
-        if new_volume.to_linear() >= settings.music_vol {
-            sink.set_volume(Volume::Linear(settings.music_vol));
+) {
+    q_fades.iter_mut().for_each(|(entity, mut fade)| {
+        fade.0 += time.delta_secs() / FADE_TIME;
+        if fade.0 >= 1.0 {
+            fade.0 = 1.0;
             commands.entity(entity).remove::<AudioFadeIn>();
         }
     });
 }
 
-fn fade_out<S>(
+fn fade_out(
     mut commands: Commands,
-    mut q_audio_sink: Query<(Entity, &mut S), With<AudioFadeOut>>,
-    settings: Res<Persistent<Settings>>,
+    mut q_fades: Query<(Entity, &mut AudioFade), With<AudioFadeOut>>,
     time: Res<Time>,
-) where
-    S: Component<Mutability = Mutable> + AudioSinkPlayback,
-{
-    q_audio_sink.iter_mut().for_each(|(entity, mut sink)| {
-        let current_volume = sink.volume();
-        let new_volume =
-            current_volume - Volume::Linear((time.delta_secs() / FADE_TIME) * settings.music_vol);
-        sink.set_volume(new_volume);
-        if new_volume.to_linear() <= 0.0 {
+) {
+    q_fades.iter_mut().for_each(|(entity, mut fade)| {
+        fade.0 -= time.delta_secs() / FADE_TIME;
+        if fade.0 <= 0.0 {
             commands.entity(entity).despawn();
         }
     });
 }
 
-pub fn audio_fade_out<T: Component + AudioType>(
-    mut commands: Commands,
-    q_audio: Query<Entity, (With<T>, Without<AudioFadeIn>)>,
-) {
+fn update_music_duck(mut duck: ResMut<MusicDuck>, time: Res<Time>) {
+    let target = duck.active as u8 as f32;
+    if duck.level != target {
+        let step = time.delta_secs() / MusicDuck::DUCK_TIME;
+        duck.level = match duck.level < target {
+            true => (duck.level + step).min(target),
+            false => (duck.level - step).max(target),
+        };
+    }
+}
+
+// Applied every frame so settings changes reach sinks that are already playing
+fn update_bus_volumes<T, S>(
+    mut q_sinks: Query<
+        (
+            &mut S,
+            Option<&AudioFade>,
+            Option<&AudioGain>,
+            Has<Unducked>,
+        ),
+        With<T>,
+    >,
+    settings: Res<Persistent<Settings>>,
+    duck: Res<MusicDuck>,
+) where
+    T: AudioType,
+    S: Component<Mutability = Mutable> + AudioSinkPlayback,
+{
+    let bus_volume = settings.master_vol * T::bus_volume(&settings);
+
+    q_sinks
+        .iter_mut()
+        .for_each(|(mut sink, fade, gain, unducked)| {
+            let fade = fade.map_or(1.0, |AudioFade(fade)| fade.clamp(0.0, 1.0));
+            let gain = gain.map_or(1.0, |AudioGain(gain)| *gain);
+            let duck = match T::DUCKED && !unducked {
+                true => duck.factor(),
+                false => 1.0,
+            };
+
+            let volume = Volume::Linear(bus_volume * fade * gain * duck);
+            if sink.volume() != volume {
+                sink.set_volume(volume);
+            }
+        });
+}
+
+use bevy::ecs::component::Mutable;
+
+pub fn audio_fade_out<T: AudioType>(mut commands: Commands, q_audio: Query<Entity, With<T>>) {
     q_audio.iter().for_each(|entity| {
-        commands.entity(entity).insert(AudioFadeOut);
+        commands
+            .entity(entity)
+            .remove::<AudioFadeIn>()
+            .insert(AudioFadeOut);
     });
 }
 
-pub fn audio_kill_all<T: Component + AudioType>(
-    mut commands: Commands,
-    q_audio: Query<Entity, With<T>>,
-) {
+pub fn audio_kill_all<T: AudioType>(mut commands: Commands, q_audio: Query<Entity, With<T>>) {
     q_audio.iter().for_each(|entity| {
         commands.entity(entity).despawn();
     });
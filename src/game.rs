@@ -58,6 +58,7 @@ enum GameState {
 struct Player;
 
 #[derive(Debug, Component)]
+#[require(effects::CameraEffects)]
 struct WorldCamera;
 
 #[derive(Debug, Component)]
@@ -125,6 +126,30 @@ fn disable_movement(
     }
 }
 
+fn pause_game(
+    current_app_state: Res<State<AppState>>,
+    mut next_app_state: ResMut<NextState<AppState>>,
+) {
+    if let AppState::Game { can_move, .. } = current_app_state.get() {
+        next_app_state.set(AppState::Game {
+            paused:   true,
+            can_move: *can_move,
+        });
+    }
+}
+
+fn unpause_game(
+    current_app_state: Res<State<AppState>>,
+    mut next_app_state: ResMut<NextState<AppState>>,
+) {
+    if let AppState::Game { can_move, .. } = current_app_state.get() {
+        next_app_state.set(AppState::Game {
+            paused:   false,
+            can_move: *can_move,
+        });
+    }
+}
+
 #[derive(Debug, Default, Deref, DerefMut, Resource)]
 struct AssetTracker {
     #[deref]
@@ -7,8 +7,7 @@ use bevy_ecs_tiled::prelude::*;
 use bevy_rand::prelude::*;
 
 use crate::{
-    RENDER_LAYER_WORLD, WINDOW_HEIGHT, WINDOW_WIDTH, animation::SpriteAnimation, despawn_screen,
-    game::effects::*,
+    RENDER_LAYER_WORLD, WINDOW_HEIGHT, WINDOW_WIDTH, animation::*, despawn_screen, game::effects::*,
 };
 
 use super::*;
@@ -50,8 +49,7 @@ pub fn bones_plugin(app: &mut App) {
                 player_jump.run_if(
                     in_state(BonesState::Playing).and(just_pressed_jump.and(player_grounded)),
                 ),
-                update_player_state,
-                update_player_animation,
+                update_player_animator,
             )
                 .chain()
                 .run_if(in_state(MovementEnabled)),
@@ -61,7 +59,7 @@ pub fn bones_plugin(app: &mut App) {
             enemy_dive,
             enemy_land,
             enemy_recover,
-            update_enemy_animation,
+            update_enemy_animators,
             enemy_fire,
         )
             .run_if(in_state(BonesState::Playing)),
@@ -74,11 +72,7 @@ pub fn bones_plugin(app: &mut App) {
         )
             .run_if(in_state(BonesState::Playing)),
     )
-    .add_systems(OnEnter(BonesState::Ending), fade_to_black)
-    .add_systems(
-        Update,
-        conclude_bones.run_if(in_state(BonesState::Ending).and(on_event::<FadeIn>)),
-    )
+    .add_systems(OnEnter(BonesState::Ending), fade_to_conclusion)
     .add_systems(
         OnExit(GameState::Bones),
         (despawn_screen::<OnBones>, fade_from_black),
@@ -181,15 +175,6 @@ struct Grounded {
     forward: Option<Vec2>,
 }
 
-#[derive(Debug, Component, Default, PartialEq, Reflect)]
-#[reflect(Component)]
-enum PlayerState {
-    #[default]
-    Run,
-    Still,
-    Jump,
-}
-
 fn setup_player(
     mut commands: Commands,
     mut asset_tracker: ResMut<AssetTracker>,
@@ -197,10 +182,9 @@ fn setup_player(
 ) {
     const PLAYER_START: Vec3 = vec3(384.0, 180.0, 1.0);
 
-    let player_image = asset_server.load("sprites/bucko/escape.png");
-    let layout = TextureAtlasLayout::from_grid(UVec2::splat(64), 8, 8, None, None);
-    let layout = asset_server.add(layout);
-    asset_tracker.push(player_image.clone().untyped());
+    let (animations, sprite) =
+        SpriteAnimations::load(&asset_server, "sprites/bucko/escape.aseprite.json");
+    asset_tracker.push(animations.clone().untyped());
 
     let player_collision_layers = CollisionLayers::new(
         [ColliderLayer::Player],
@@ -217,12 +201,13 @@ fn setup_player(
             Player,
             Name::new("Bucko"),
             BonesHealth(PLAYER_MAX_HEALTH),
-            PlayerState::default(),
             Transform::from_translation(PLAYER_START),
             (
                 // Visual
-                Sprite::from_atlas_image(player_image, TextureAtlas { layout, index: 0 }),
-                SpriteAnimation::new(0, 7, 12).looping(),
+                sprite,
+                animations,
+                player_animator(),
+                SpriteAnimation::set_frame(0),
                 Visibility::default(),
                 RENDER_LAYER_WORLD,
             ),
@@ -239,7 +224,8 @@ fn setup_player(
                 Grounded::default(),
             ),
         ))
-        .observe(player_damage);
+        .observe(player_damage)
+        .observe(update_player_run_fps);
 }
 
 #[derive(Debug, Component)]
@@ -292,16 +278,9 @@ fn setup_enemies(
     const ENEMY_START: Vec3 = vec3(64.0, 180.0, 1.0);
     const ENEMY_SPACING: f32 = 48.0;
 
-    let enemy_image = asset_server.load("sprites/ucko/crawl.png");
-    let layout = TextureAtlasLayout::from_grid(UVec2::splat(64), 8, 1, None, None);
-    let layout = asset_server.add(layout);
-    asset_tracker.push(enemy_image.clone().untyped());
-
-    let enemy_sprite = Sprite {
-        image: enemy_image,
-        texture_atlas: Some(layout.into()),
-        ..default()
-    };
+    let (enemy_animations, enemy_sprite) =
+        SpriteAnimations::load(&asset_server, "sprites/ucko/crawl.anim.ron");
+    asset_tracker.push(enemy_animations.clone().untyped());
 
     let enemy_collision_layers = CollisionLayers::new(
         [ColliderLayer::Enemy],
@@ -334,7 +313,9 @@ fn setup_enemies(
             (
                 // Visual
                 enemy_sprite.clone(),
-                SpriteAnimation::new(0, 7, 12).looping(),
+                enemy_animations.clone(),
+                enemy_animator(),
+                SpriteAnimation::set_frame(0),
                 Visibility::default(),
                 RENDER_LAYER_WORLD,
             ),
@@ -364,7 +345,8 @@ fn setup_enemies(
 
 #[derive(Debug, Resource)]
 struct BonesEffects {
-    fireball: Sprite,
+    fireball:            Sprite,
+    fireball_animations: SpriteAnimations,
 }
 
 fn setup_sprite_effects(
@@ -372,15 +354,13 @@ fn setup_sprite_effects(
     mut asset_tracker: ResMut<AssetTracker>,
     asset_server: Res<AssetServer>,
 ) {
-    let fireball_image = asset_server.load("sprites/effects/fireball.png");
-    asset_tracker.push(fireball_image.clone().untyped());
-
-    let fireball_layout = TextureAtlasLayout::from_grid(UVec2::splat(32), 6, 1, None, None);
-    let fireball_layout = asset_server.add(fireball_layout);
-    let fireball_sprite = Sprite::from_atlas_image(fireball_image, fireball_layout.into());
+    let (fireball_animations, fireball) =
+        SpriteAnimations::load(&asset_server, "sprites/effects/fireball.anim.ron");
+    asset_tracker.push(fireball_animations.clone_weak().untyped());
 
     commands.insert_resource(BonesEffects {
-        fireball: fireball_sprite,
+        fireball,
+        fireball_animations,
     });
 }
 
@@ -430,12 +410,26 @@ fn progress_map(
 
 fn player_damage(
     trigger: Trigger<OnCollisionStart>,
-    q_hitboxes: Query<(), With<DamageCollider>>,
-    mut player_health: Single<&mut BonesHealth, With<Player>>,
+    mut commands: Commands,
+    q_hitboxes: Query<&GlobalTransform, With<DamageCollider>>,
+    player: Single<(&mut BonesHealth, &GlobalTransform), With<Player>>,
 ) {
-    if q_hitboxes.contains(trigger.collider) {
-        player_health.0 = player_health.saturating_sub(1);
-    }
+    const DAMAGE_KICK: f32 = 8.0; // pixels
+
+    let Ok(hitbox_transform) = q_hitboxes.get(trigger.collider) else {
+        return;
+    };
+
+    let (mut player_health, player_transform) = player.into_inner();
+    player_health.0 = player_health.saturating_sub(1);
+
+    // Knocked away from whatever hit the player
+    let direction = (player_transform.translation() - hitbox_transform.translation())
+        .truncate()
+        .normalize_or_zero();
+    commands.trigger(CameraImpulse::Trauma(0.5));
+    commands.trigger(CameraImpulse::Kick(direction * DAMAGE_KICK));
+    commands.trigger(CameraImpulse::HitStop(0.1));
 }
 
 fn update_buckos_grounded(
@@ -499,57 +493,68 @@ fn player_jump(mut player_velocity: Single<&mut LinearVelocity, With<Player>>) {
     player_velocity.0 += JUMP_IMPULSE;
 }
 
-fn update_player_state(
-    player: Single<(&Grounded, &LinearVelocity, &mut PlayerState), With<Player>>,
-) {
-    const RUN_THRESHOLD: f32 = 4.0;
-
-    let (grounded, velocity, mut state) = player.into_inner();
-
-    let new_state = match grounded.is_some() {
-        true => match velocity.x > RUN_THRESHOLD {
-            true => PlayerState::Run,
-            false => PlayerState::Still,
-        },
-        false => PlayerState::Jump,
-    };
-
-    state.set_if_neq(new_state);
+const RUN_THRESHOLD: f32 = 4.0;
+
+// Clips have a variant per missing bone, e.g. "run_3"
+fn player_animator() -> SpriteAnimator {
+    let run = [
+        AnimatorCondition::Is("grounded"),
+        AnimatorCondition::Above("speed", RUN_THRESHOLD),
+    ];
+    let still = [
+        AnimatorCondition::Is("grounded"),
+        AnimatorCondition::Below("speed", RUN_THRESHOLD),
+    ];
+    let jump = [AnimatorCondition::Not("grounded")];
+
+    SpriteAnimator::new("run")
+        .with_state(
+            "run",
+            AnimatorState::new("run")
+                .to("jump", jump)
+                .to("still", still),
+        )
+        .with_state(
+            "still",
+            AnimatorState::new("still").to("jump", jump).to("run", run),
+        )
+        .with_state(
+            "jump",
+            AnimatorState::new("jump").to("run", run).to("still", still),
+        )
 }
 
-fn update_player_animation(
+fn update_player_animator(
     player: Single<
         (
-            Ref<BonesHealth>,
-            Ref<PlayerState>,
+            &BonesHealth,
+            &Grounded,
             &LinearVelocity,
-            &mut SpriteAnimation,
+            &mut SpriteAnimator,
         ),
         With<Player>,
     >,
 ) {
     const ANIMATION_ROWS: u8 = 7;
-    const SPRITES_PER_ROW: usize = 8;
-    const STILLS_INDEX: usize = 56;
-    const MIN_FPS: u8 = 6;
-
-    let (health, state, velocity, mut animation) = player.into_inner();
-
-    let current_row = (PLAYER_MAX_HEALTH - health.0).min(ANIMATION_ROWS - 1) as usize;
-    let new_index = SPRITES_PER_ROW * current_row;
-
-    if state.is_changed() || health.is_changed() {
-        *animation = match *state {
-            PlayerState::Run => SpriteAnimation::new(new_index, new_index + 7, 12).looping(),
-            PlayerState::Jump => SpriteAnimation::set_frame(new_index), // first frame of row is jump
-            PlayerState::Still => SpriteAnimation::set_frame(STILLS_INDEX + current_row),
-        };
-
-        if matches!(*state, PlayerState::Run) {
-            animation
-                .as_mut()
-                .change_fps(MIN_FPS + (velocity.x / 8.0) as u8);
-        }
+
+    let (health, grounded, velocity, mut animator) = player.into_inner();
+
+    animator.set_bool("grounded", grounded.is_some());
+    animator.set_float("speed", velocity.x);
+    animator.set_variant((PLAYER_MAX_HEALTH - health.0).min(ANIMATION_ROWS - 1));
+}
+
+// Runs faster the faster Bucko goes
+fn update_player_run_fps(
+    trigger: Trigger<AnimatorClipChanged>,
+    player: Single<(&LinearVelocity, &mut SpriteAnimation), With<Player>>,
+) {
+    const MIN_FPS: f32 = 6.0;
+
+    let (velocity, mut animation) = player.into_inner();
+
+    if trigger.state == "run" {
+        animation.change_fps(MIN_FPS + (velocity.x / 8.0).max(0.0));
     }
 }
 
@@ -577,15 +582,28 @@ fn update_enemies_velocity(
         });
 }
 
-fn update_enemy_animation(
-    mut q_enemies: Query<(&UckoState, &mut SpriteAnimation), (With<Ucko>, Changed<UckoState>)>,
+fn enemy_animator() -> SpriteAnimator {
+    SpriteAnimator::new("chase")
+        .with_any_transition("sprint", [AnimatorCondition::Is("sprinting")])
+        .with_any_transition("chase", [AnimatorCondition::Is("chasing")])
+        .with_any_transition(
+            "still",
+            [
+                AnimatorCondition::Not("sprinting"),
+                AnimatorCondition::Not("chasing"),
+            ],
+        )
+        .with_state("chase", AnimatorState::new("chase"))
+        .with_state("sprint", AnimatorState::new("sprint"))
+        .with_state("still", AnimatorState::new("still"))
+}
+
+fn update_enemy_animators(
+    mut q_enemies: Query<(&UckoState, &mut SpriteAnimator), (With<Ucko>, Changed<UckoState>)>,
 ) {
-    q_enemies.iter_mut().for_each(|(state, mut animation)| {
-        *animation = match *state {
-            UckoState::Chase => SpriteAnimation::new(0, 7, 12).looping(),
-            UckoState::Sprint => SpriteAnimation::new(0, 7, 16).looping(),
-            _ => SpriteAnimation::set_frame(0),
-        }
+    q_enemies.iter_mut().for_each(|(state, mut animator)| {
+        animator.set_bool("chasing", matches!(state, UckoState::Chase));
+        animator.set_bool("sprinting", matches!(state, UckoState::Sprint));
     });
 }
 
@@ -649,6 +667,7 @@ fn enemy_jump(
 }
 
 fn enemy_dive(
+    mut commands: Commands,
     player: Single<Entity, With<Player>>,
     mut q_enemies: Query<(&mut UckoState, &RayHits, &mut LinearVelocity), With<Ucko>>,
 ) {
@@ -661,10 +680,12 @@ fn enemy_dive(
         .for_each(|(mut state, _ray_hits, mut velocity)| {
             velocity.0 += DIVE_IMPULSE;
             *state = UckoState::Dive;
+            commands.trigger(CameraImpulse::Zoom(0.05));
         });
 }
 
 fn enemy_land(
+    mut commands: Commands,
     mut q_enemies: Query<
         (&mut UckoState, &Grounded, &mut LinearVelocity),
         (With<Ucko>, Changed<Grounded>),
@@ -679,6 +700,8 @@ fn enemy_land(
             *state = match *state {
                 UckoState::Dive => {
                     velocity.set_if_neq(LinearVelocity::ZERO);
+                    commands.trigger(CameraImpulse::Trauma(0.3));
+                    commands.trigger(CameraImpulse::Kick(vec2(0.0, -4.0)));
                     UckoState::Recover
                 }
                 UckoState::Jump => UckoState::Chase,
@@ -731,7 +754,12 @@ struct Fireball;
 #[derive(Debug, Component)]
 struct FireballCollider;
 
-fn spawn_fireball(In(origin): In<Vec3>, mut commands: Commands, effects: Res<BonesEffects>) {
+fn spawn_fireball(
+    In(origin): In<Vec3>,
+    mut commands: Commands,
+    effects: Res<BonesEffects>,
+    sets: Res<Assets<SpriteAnimationSet>>,
+) {
     const FIREBALL_VELOCITY: Vec2 = vec2(512.0, 256.0);
     // info!("Fireball spawned at {}", origin.truncate());
 
@@ -750,7 +778,8 @@ fn spawn_fireball(In(origin): In<Vec3>, mut commands: Commands, effects: Res<Bon
             Fireball,
             Name::new("Fireball"),
             effects.fireball.clone(),
-            SpriteAnimation::new(0, 5, 12).looping(),
+            effects.fireball_animations.clone(),
+            effects.fireball_animations.clip(&sets, "burn"),
             RigidBody::Dynamic,
             AngularVelocity(-0.4),
             LinearVelocity(FIREBALL_VELOCITY),
@@ -789,6 +818,12 @@ fn fireball_land(
     }
 }
 
+fn fade_to_conclusion(mut commands: Commands) {
+    commands
+        .transition(Transition::cover(TransitionKind::Fade, Color::BLACK))
+        .observe(after_transition(conclude_bones));
+}
+
 fn conclude_bones(mut commands: Commands) {
     commands.insert_resource(BonesTimer::default());
     commands.insert_resource(Gravity::ZERO);
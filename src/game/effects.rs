@@ -2,12 +2,17 @@ use bevy::{animation::*, color::palettes::css::*, prelude::*};
 
 use super::*;
 use crate::{RENDER_LAYER_OVERLAY, WINDOW_HEIGHT, WINDOW_WIDTH};
+use camera::camera_effects_plugin;
+pub use camera::*;
+use transition::transition_plugin;
+pub use transition::*;
+
+mod camera;
+mod transition;
 
 pub fn effects_plugin(app: &mut App) {
-    app.add_systems(Startup, (spawn_cinematic_bars, spawn_fades))
-        // .add_systems(Update, update_fade)
-        .add_event::<FadeIn>()
-        .add_event::<FadeOut>()
+    app.add_plugins((camera_effects_plugin, transition_plugin))
+        .add_systems(Startup, spawn_cinematic_bars)
         .add_event::<CinematicBarsIn>()
         .add_event::<CinematicBarsOut>();
 }
@@ -18,18 +23,6 @@ pub struct EffectsNodes {
     node_out: AnimationNodeIndex,
 }
 
-#[derive(Debug, Component)]
-pub struct FadeBlack;
-
-#[derive(Debug, Component)]
-pub struct FadeWhite;
-
-#[derive(Debug, Clone, Copy, Event)]
-pub struct FadeIn;
-
-#[derive(Debug, Clone, Copy, Event)]
-pub struct FadeOut;
-
 #[derive(Debug, Clone)]
 pub struct SpriteAlphaProperty;
 
@@ -68,156 +61,37 @@ impl AnimatableProperty for SpriteAlphaProperty {
     }
 }
 
-const FADE_DURATION: f32 = 2.0; // seconds
-
-fn spawn_fades(mut commands: Commands, asset_server: Res<AssetServer>) {
-    let fade_black_name = Name::new("Fade to Black");
-    let fade_white_name = Name::new("Fade to White");
-
-    let fade_black_target_id = AnimationTargetId::from_name(&fade_black_name);
-    let fade_white_target_id = AnimationTargetId::from_name(&fade_white_name);
-
-    let domain = interval(0.0, FADE_DURATION).unwrap();
-
-    let (fade_black_graph, fade_black_nodes) = AnimationGraph::from_clips([
-        asset_server.add({
-            let mut fade_black_in_clip = AnimationClip::default();
-            fade_black_in_clip.add_curve_to_target(
-                fade_black_target_id,
-                AnimatableCurve::new(
-                    SpriteAlphaProperty,
-                    EasingCurve::new(0.0, 1.0, EaseFunction::Linear)
-                        .reparametrize_linear(domain)
-                        .unwrap(),
-                ),
-            );
-            fade_black_in_clip.add_event_fn(FADE_DURATION, |commands, _entity, _time, _weight| {
-                commands.send_event(FadeIn);
-            });
-            fade_black_in_clip
-        }),
-        asset_server.add({
-            let mut fade_black_out_clip = AnimationClip::default();
-            fade_black_out_clip.add_curve_to_target(
-                fade_black_target_id,
-                AnimatableCurve::new(
-                    SpriteAlphaProperty,
-                    EasingCurve::new(1.0, 0.0, EaseFunction::Linear)
-                        .reparametrize_linear(domain)
-                        .unwrap(),
-                ),
-            );
-            fade_black_out_clip.add_event_fn(FADE_DURATION, |commands, _entity, _time, _weight| {
-                commands.send_event(FadeOut);
-            });
-            fade_black_out_clip
-        }),
-    ]);
-    let (fade_white_graph, fade_white_nodes) = AnimationGraph::from_clips([
-        asset_server.add({
-            let mut fade_white_in_clip = AnimationClip::default();
-            fade_white_in_clip.add_curve_to_target(
-                fade_white_target_id,
-                AnimatableCurve::new(
-                    SpriteAlphaProperty,
-                    EasingCurve::new(0.0, 1.0, EaseFunction::Linear)
-                        .reparametrize_linear(domain)
-                        .unwrap(),
-                ),
-            );
-            fade_white_in_clip.add_event_fn(FADE_DURATION, |commands, _entity, _time, _weight| {
-                commands.send_event(FadeIn);
-            });
-            fade_white_in_clip
-        }),
-        asset_server.add({
-            let mut fade_white_out_clip = AnimationClip::default();
-            fade_white_out_clip.add_curve_to_target(
-                fade_white_target_id,
-                AnimatableCurve::new(
-                    SpriteAlphaProperty,
-                    EasingCurve::new(1.0, 0.0, EaseFunction::Linear)
-                        .reparametrize_linear(domain)
-                        .unwrap(),
-                ),
-            );
-            fade_white_out_clip.add_event_fn(FADE_DURATION, |commands, _entity, _time, _weight| {
-                commands.send_event(FadeOut);
-            });
-            fade_white_out_clip
-        }),
-    ]);
-
-    let fade_black_graph_handle = asset_server.add(fade_black_graph);
-    let fade_white_graph_handle = asset_server.add(fade_white_graph);
-
-    let fade_black_entity = commands.spawn_empty().id();
-    let fade_white_entity = commands.spawn_empty().id();
-
-    commands.entity(fade_black_entity).insert((
-        FadeBlack,
-        fade_black_name,
-        Sprite::from_color(BLACK.with_alpha(0.0), vec2(WINDOW_WIDTH, WINDOW_HEIGHT)),
-        AnimationPlayer::default(),
-        AnimationGraphHandle(fade_black_graph_handle),
-        AnimationTarget {
-            id:     fade_black_target_id,
-            player: fade_black_entity,
-        },
-        EffectsNodes {
-            node_in:  fade_black_nodes[0],
-            node_out: fade_black_nodes[1],
-        },
-        Transform::from_translation(Vec3::ZERO.with_z(Z_EFFECTS)),
-        RENDER_LAYER_OVERLAY,
-    ));
-    commands.entity(fade_white_entity).insert((
-        FadeWhite,
-        fade_white_name,
-        Sprite::from_color(WHITE.with_alpha(0.0), vec2(WINDOW_WIDTH, WINDOW_HEIGHT)),
-        AnimationPlayer::default(),
-        AnimationGraphHandle(fade_white_graph_handle),
-        AnimationTarget {
-            id:     fade_white_target_id,
-            player: fade_white_entity,
-        },
-        EffectsNodes {
-            node_in:  fade_white_nodes[0],
-            node_out: fade_white_nodes[1],
-        },
-        Transform::from_translation(Vec3::ZERO.with_z(Z_EFFECTS)),
-        RENDER_LAYER_OVERLAY,
-    ));
-}
-
-pub fn fade_to_black(fade_black: Single<(&mut AnimationPlayer, &EffectsNodes), With<FadeBlack>>) {
-    let (mut player, nodes) = fade_black.into_inner();
-    player.stop_all().play(nodes.node_in);
-}
+#[derive(Debug, Clone)]
+pub struct SpriteColorProperty;
 
-pub fn fade_from_black(fade_black: Single<(&mut AnimationPlayer, &EffectsNodes), With<FadeBlack>>) {
-    let (mut player, nodes) = fade_black.into_inner();
-    player.stop_all().play(nodes.node_out);
-}
+impl AnimatableProperty for SpriteColorProperty {
+    type Property = Srgba;
 
-pub fn fade_to_white(fade_white: Single<(&mut AnimationPlayer, &EffectsNodes), With<FadeWhite>>) {
-    let (mut player, nodes) = fade_white.into_inner();
-    player.stop_all().play(nodes.node_in);
-}
+    fn evaluator_id(&self) -> EvaluatorId {
+        EvaluatorId::Type(std::any::TypeId::of::<Self>())
+    }
 
-pub fn fade_from_white(fade_white: Single<(&mut AnimationPlayer, &EffectsNodes), With<FadeWhite>>) {
-    let (mut player, nodes) = fade_white.into_inner();
-    player.stop_all().play(nodes.node_out);
-}
+    fn get_mut<'a>(
+        &self,
+        entity: &'a mut AnimationEntityMut,
+    ) -> Result<&'a mut Self::Property, AnimationEvaluationError> {
+        use std::any::TypeId;
 
-type BothFades = Or<(With<FadeBlack>, With<FadeWhite>)>;
-pub fn fade_from_whatever(mut q_fades: Query<(&mut AnimationPlayer, &EffectsNodes), BothFades>) {
-    q_fades
-        .iter_mut()
-        .filter(|(player, nodes)| player.is_playing_animation(nodes.node_in))
-        .for_each(|(mut player, node)| {
-            player.stop_all().play(node.node_out);
-        });
+        let sprite = entity
+            .get_mut::<Sprite>()
+            .ok_or(AnimationEvaluationError::ComponentNotPresent(TypeId::of::<
+                Sprite,
+            >(
+            )))?
+            .into_inner();
+        match sprite.color {
+            Color::Srgba(ref mut srgba) => Ok(srgba),
+            _ => Err(AnimationEvaluationError::PropertyNotPresent(TypeId::of::<
+                Srgba,
+            >(
+            ))),
+        }
+    }
 }
 
 #[derive(Debug, Component)]
@@ -0,0 +1,200 @@
+use bevy::{prelude::*, transform::TransformSystem};
+use bevy_persistent::Persistent;
+
+use super::*;
+
+pub fn camera_effects_plugin(app: &mut App) {
+    app.add_systems(PreUpdate, (remove_camera_effects, update_hit_stop))
+        .add_systems(
+            PostUpdate,
+            apply_camera_effects.before(TransformSystem::TransformPropagate),
+        )
+        .add_observer(apply_camera_impulse)
+        .init_resource::<HitStop>();
+}
+
+// Pixels at full trauma
+const MAX_SHAKE_OFFSET: f32 = 12.0;
+// Radians at full trauma
+const MAX_SHAKE_ANGLE: f32 = 0.03;
+// Trauma lost per second
+const TRAUMA_DECAY: f32 = 1.5;
+// How quickly kicks and zooms ease back, per second
+const KICK_RECOVERY: f32 = 12.0;
+const ZOOM_RECOVERY: f32 = 8.0;
+// Speed of Time<Virtual> during a hit-stop
+const HIT_STOP_SPEED: f32 = 0.05;
+
+// Triggered globally to affect every WorldCamera, or on a camera to affect only it
+#[derive(Debug, Clone, Copy, Event)]
+pub enum CameraImpulse {
+    // Adds to the camera's trauma, clamped to 1, shake grows with its square
+    Trauma(f32),
+    // Pushes the camera by an offset in pixels that springs back
+    Kick(Vec2),
+    // Zooms in by a fraction of the view and eases back out, orthographic only
+    Zoom(f32),
+    // Slows Time<Virtual> to a crawl for a number of real seconds
+    HitStop(f32),
+    // Holds trauma up for a number of real seconds as it runs down, e.g. in cutscenes
+    Rumble(f32, f32),
+}
+
+// Shake, kicks and zoom pulses layered on top of whatever positions the camera.
+// They're removed before Update and reapplied after, so camera systems never
+// see them
+#[derive(Debug, Component)]
+pub struct CameraEffects {
+    // World units per pixel of shake and kick, for cameras that aren't 2D
+    scale:  f32,
+    trauma: f32,
+    kick:   Vec2,
+    zoom:   f32,
+    // Trauma held up by a Rumble and the time it runs down over
+    rumble: Option<(f32, Timer)>,
+
+    // Applied this frame, undone on the next
+    offset: Vec2,
+    angle:  f32,
+    zoomed: f32,
+}
+
+impl Default for CameraEffects {
+    fn default() -> Self {
+        CameraEffects::scaled(1.0)
+    }
+}
+
+impl CameraEffects {
+    pub fn scaled(scale: f32) -> Self {
+        CameraEffects {
+            scale,
+            trauma: 0.0,
+            kick: Vec2::ZERO,
+            zoom: 0.0,
+            rumble: None,
+            offset: Vec2::ZERO,
+            angle: 0.0,
+            zoomed: 0.0,
+        }
+    }
+}
+
+#[derive(Debug, Default, Resource)]
+struct HitStop(Option<Timer>);
+
+fn apply_camera_impulse(
+    trigger: Trigger<CameraImpulse>,
+    mut q_cameras: Query<&mut CameraEffects, With<WorldCamera>>,
+    mut hit_stop: ResMut<HitStop>,
+    mut time: ResMut<Time<Virtual>>,
+    settings: Res<Persistent<Settings>>,
+) {
+    let motion = settings.camera_motion;
+    if motion <= 0.0 {
+        return;
+    }
+
+    let impulse = *trigger.event();
+    let apply = |mut effects: Mut<CameraEffects>| match impulse {
+        CameraImpulse::Trauma(trauma) => {
+            effects.trauma = (effects.trauma + trauma * motion).min(1.0);
+        }
+        CameraImpulse::Kick(kick) => effects.kick += kick * motion,
+        CameraImpulse::Zoom(zoom) => effects.zoom += zoom * motion,
+        CameraImpulse::Rumble(trauma, secs) => {
+            effects.rumble = Some((trauma * motion, Timer::from_seconds(secs, TimerMode::Once)));
+        }
+        CameraImpulse::HitStop(_) => (),
+    };
+
+    let target = trigger.target();
+    if target == Entity::PLACEHOLDER {
+        q_cameras.iter_mut().for_each(apply);
+    } else if let Ok(effects) = q_cameras.get_mut(target) {
+        apply(effects);
+    }
+
+    if let CameraImpulse::HitStop(secs) = impulse {
+        let secs = secs * motion;
+        let remaining = hit_stop.0.as_ref().map_or(0.0, Timer::remaining_secs);
+        if secs > remaining {
+            hit_stop.0 = Some(Timer::from_seconds(secs, TimerMode::Once));
+            time.set_relative_speed(HIT_STOP_SPEED);
+        }
+    }
+}
+
+fn update_hit_stop(
+    mut hit_stop: ResMut<HitStop>,
+    mut time: ResMut<Time<Virtual>>,
+    real_time: Res<Time<Real>>,
+) {
+    let Some(timer) = hit_stop.0.as_mut() else {
+        return;
+    };
+
+    if timer.tick(real_time.delta()).finished() {
+        hit_stop.0 = None;
+        time.set_relative_speed(1.0);
+    }
+}
+
+fn remove_camera_effects(mut q_cameras: Query<(&mut CameraEffects, &mut Transform)>) {
+    q_cameras
+        .iter_mut()
+        .for_each(|(mut effects, mut transform)| {
+            transform.translation -= effects.offset.extend(0.0);
+            transform.rotate_local_z(-effects.angle);
+            transform.scale *= 1.0 + effects.zoomed;
+            effects.offset = Vec2::ZERO;
+            effects.angle = 0.0;
+            effects.zoomed = 0.0;
+        });
+}
+
+fn apply_camera_effects(
+    mut q_cameras: Query<(&mut CameraEffects, &mut Transform, &Projection)>,
+    mut rng: GlobalEntropy<WyRand>,
+    real_time: Res<Time<Real>>,
+) {
+    // Real time so shake keeps going through hit-stops
+    let delta = real_time.delta_secs();
+
+    q_cameras
+        .iter_mut()
+        .for_each(|(mut effects, mut transform, projection)| {
+            effects.trauma = (effects.trauma - TRAUMA_DECAY * delta).max(0.0);
+            effects.kick *= (-KICK_RECOVERY * delta).exp();
+            effects.zoom *= (-ZOOM_RECOVERY * delta).exp();
+
+            let rumble = effects.rumble.as_mut().map(|(trauma, timer)| {
+                timer.tick(real_time.delta());
+                (*trauma * timer.fraction_remaining(), timer.finished())
+            });
+            if let Some((trauma, finished)) = rumble {
+                effects.trauma = effects.trauma.max(trauma.min(1.0));
+                if finished {
+                    effects.rumble = None;
+                }
+            }
+
+            let shake = effects.trauma * effects.trauma;
+            let jitter = vec2(
+                random_range(&mut rng, -1.0, 1.0),
+                random_range(&mut rng, -1.0, 1.0),
+            );
+            // Offsets are in pixels on screen, so they follow the camera's scale
+            let pixel = effects.scale * transform.scale.x;
+            effects.offset = (jitter * MAX_SHAKE_OFFSET * shake + effects.kick) * pixel;
+            effects.angle = random_range(&mut rng, -1.0, 1.0) * MAX_SHAKE_ANGLE * shake;
+            effects.zoomed = match projection {
+                Projection::Orthographic(_) => effects.zoom,
+                _ => 0.0,
+            };
+
+            transform.translation += effects.offset.extend(0.0);
+            transform.rotate_local_z(effects.angle);
+            transform.scale /= 1.0 + effects.zoomed;
+        });
+}
@@ -0,0 +1,326 @@
+use bevy::{
+    prelude::*,
+    render::render_resource::{AsBindGroup, ShaderRef},
+    sprite::{AlphaMode2d, Material2d, Material2dPlugin},
+};
+
+use super::*;
+
+pub fn transition_plugin(app: &mut App) {
+    app.add_plugins(Material2dPlugin::<TransitionMaterial>::default())
+        .add_systems(Startup, spawn_transition_overlay)
+        .add_systems(
+            Update,
+            update_transitions.run_if(any_with_component::<Transition>),
+        )
+        .add_observer(start_transition);
+}
+
+const TRANSITION_DURATION: f32 = 2.0; // seconds
+// Size of the blocks the screen dissolves into with TransitionKind::Dissolve
+const DISSOLVE_CELL: f32 = 16.0;
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum TransitionKind {
+    #[default]
+    Fade,
+    // Circle closing in on the focus entity, or the middle of the screen
+    Iris,
+    // Diagonal edge sweeping from the top left corner
+    Wipe,
+    // Blocks covering the screen in a random order
+    Dissolve,
+}
+
+impl TransitionKind {
+    fn index(self) -> f32 {
+        match self {
+            TransitionKind::Fade => 0.0,
+            TransitionKind::Iris => 1.0,
+            TransitionKind::Wipe => 2.0,
+            TransitionKind::Dissolve => 3.0,
+        }
+    }
+}
+
+// Triggered only on the entity spawned by Commands::transition once it has
+// covered or revealed the screen, or once another transition replaced it.
+// The entity is despawned right after
+#[derive(Debug, Clone, Copy, Event)]
+pub struct TransitionFinished {
+    pub kind:     TransitionKind,
+    pub cover:    bool,
+    // Cut short by another transition, the screen may not be covered or revealed
+    pub replaced: bool,
+}
+
+// Covers or reveals the screen with the transition overlay, spawn it with
+// Commands::transition. Starting a new one replaces the one playing, which
+// finishes right away with TransitionFinished::replaced set
+#[derive(Debug, Clone, Component)]
+pub struct Transition {
+    kind:  TransitionKind,
+    color: Color,
+    cover: bool,
+    focus: Option<Entity>,
+    timer: Timer,
+    // Coverage of the overlay when this transition started
+    from:  f32,
+}
+
+impl Transition {
+    fn new(kind: TransitionKind, color: Color, cover: bool) -> Self {
+        Transition {
+            kind,
+            color,
+            cover,
+            focus: None,
+            timer: Timer::from_seconds(TRANSITION_DURATION, TimerMode::Once),
+            from: 0.0,
+        }
+    }
+
+    pub fn cover(kind: TransitionKind, color: impl Into<Color>) -> Self {
+        Transition::new(kind, color.into(), true)
+    }
+
+    pub fn reveal(kind: TransitionKind, color: impl Into<Color>) -> Self {
+        Transition::new(kind, color.into(), false)
+    }
+
+    pub fn duration(mut self, secs: f32) -> Self {
+        self.timer = Timer::from_seconds(secs, TimerMode::Once);
+        self
+    }
+
+    // Entity the iris closes in on, followed as it moves
+    pub fn focus(mut self, entity: Entity) -> Self {
+        self.focus = Some(entity);
+        self
+    }
+
+    fn target(&self) -> f32 {
+        match self.cover {
+            true => 1.0,
+            false => 0.0,
+        }
+    }
+}
+
+pub trait TransitionCommandsExt {
+    // Observe TransitionFinished on the returned entity to know when it's done
+    fn transition(&mut self, transition: Transition) -> EntityCommands<'_>;
+}
+
+impl TransitionCommandsExt for Commands<'_, '_> {
+    fn transition(&mut self, transition: Transition) -> EntityCommands<'_> {
+        self.spawn((Name::new("Transition"), transition))
+    }
+}
+
+// Observer for a transition entity that runs a one-shot system once it finishes,
+// e.g. commands.transition(..).observe(after_transition(warp_player)). Skipped
+// when the transition is replaced, requesters that need to go ahead regardless
+// observe TransitionFinished themselves
+pub fn after_transition<M, S>(system: S) -> impl FnMut(Trigger<TransitionFinished>, Commands)
+where
+    S: IntoSystem<(), (), M> + Copy + Send + Sync + 'static,
+{
+    move |trigger: Trigger<TransitionFinished>, mut commands: Commands| {
+        if trigger.replaced {
+            warn!("Transition replaced before it finished, skipping what waited on it");
+            return;
+        }
+        commands.run_system_cached(system);
+    }
+}
+
+#[derive(Asset, TypePath, AsBindGroup, Debug, Clone)]
+pub struct TransitionMaterial {
+    #[uniform(0)]
+    color:  LinearRgba,
+    // Coverage, kind, iris center uv
+    #[uniform(1)]
+    params: Vec4,
+    // Aspect ratio, iris radius at no coverage, dissolve columns and rows
+    #[uniform(2)]
+    extra:  Vec4,
+}
+
+impl Material2d for TransitionMaterial {
+    fn fragment_shader() -> ShaderRef {
+        "shaders/transition.wgsl".into()
+    }
+
+    fn alpha_mode(&self) -> AlphaMode2d {
+        AlphaMode2d::Blend
+    }
+}
+
+// What the overlay last showed, so a reveal picks up where a cover left off
+#[derive(Debug, Component)]
+pub struct TransitionOverlay {
+    kind:     TransitionKind,
+    color:    Color,
+    focus:    Option<Entity>,
+    coverage: f32,
+}
+
+fn spawn_transition_overlay(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<TransitionMaterial>>,
+) {
+    let material = TransitionMaterial {
+        color:  LinearRgba::NONE,
+        params: Vec4::ZERO,
+        extra:  vec4(
+            WINDOW_WIDTH / WINDOW_HEIGHT,
+            0.0,
+            WINDOW_WIDTH / DISSOLVE_CELL,
+            WINDOW_HEIGHT / DISSOLVE_CELL,
+        ),
+    };
+
+    commands.spawn((
+        Name::new("Transition Overlay"),
+        TransitionOverlay {
+            kind:     TransitionKind::Fade,
+            color:    Color::NONE,
+            focus:    None,
+            coverage: 0.0,
+        },
+        Mesh2d(meshes.add(Rectangle::new(WINDOW_WIDTH, WINDOW_HEIGHT))),
+        MeshMaterial2d(materials.add(material)),
+        Transform::from_translation(Vec3::ZERO.with_z(Z_EFFECTS)),
+        RENDER_LAYER_OVERLAY,
+    ));
+}
+
+fn start_transition(
+    trigger: Trigger<OnAdd, Transition>,
+    mut commands: Commands,
+    mut q_transitions: Query<(Entity, &mut Transition)>,
+    overlay: Single<&TransitionOverlay>,
+) {
+    let started = trigger.target();
+    q_transitions
+        .iter()
+        .filter(|(entity, _)| *entity != started)
+        .for_each(|(entity, transition)| {
+            let event = TransitionFinished {
+                kind:     transition.kind,
+                cover:    transition.cover,
+                replaced: true,
+            };
+            commands.trigger_targets(event, entity);
+            commands.entity(entity).despawn();
+        });
+
+    if let Ok((_, mut transition)) = q_transitions.get_mut(started) {
+        transition.from = overlay.coverage;
+    }
+}
+
+// Iris center in uv of the overlay and the radius reaching its furthest corner
+fn iris(
+    focus: Option<Entity>,
+    world_camera: Option<&(&Camera, &GlobalTransform)>,
+    q_focus: &Query<&GlobalTransform>,
+    aspect: f32,
+) -> (Vec2, f32) {
+    let center = focus
+        .and_then(|entity| q_focus.get(entity).ok())
+        .zip(world_camera)
+        .and_then(|(focus_transform, (camera, camera_transform))| {
+            let position = camera
+                .world_to_viewport(camera_transform, focus_transform.translation())
+                .ok()?;
+            Some(position / camera.logical_viewport_size()?)
+        })
+        .unwrap_or(Vec2::splat(0.5));
+
+    let scaled = center * vec2(aspect, 1.0);
+    let radius = [
+        vec2(0.0, 0.0),
+        vec2(aspect, 0.0),
+        vec2(0.0, 1.0),
+        vec2(aspect, 1.0),
+    ]
+    .into_iter()
+    .map(|corner| corner.distance(scaled))
+    .fold(0.0, f32::max);
+    (center, radius)
+}
+
+fn update_transitions(
+    mut commands: Commands,
+    transition: Single<(Entity, &mut Transition)>,
+    overlay: Single<(&mut TransitionOverlay, &MeshMaterial2d<TransitionMaterial>)>,
+    world_camera: Option<Single<(&Camera, &GlobalTransform), With<WorldCamera>>>,
+    q_focus: Query<&GlobalTransform>,
+    mut materials: ResMut<Assets<TransitionMaterial>>,
+    time: Res<Time>,
+) {
+    let (entity, mut transition) = transition.into_inner();
+    let (mut overlay, material) = overlay.into_inner();
+
+    transition.timer.tick(time.delta());
+    let coverage = transition
+        .from
+        .lerp(transition.target(), transition.timer.fraction());
+
+    *overlay = TransitionOverlay {
+        kind: transition.kind,
+        color: transition.color,
+        focus: transition.focus,
+        coverage,
+    };
+
+    if let Some(material) = materials.get_mut(material) {
+        let aspect = material.extra.x;
+        let (center, radius) = iris(transition.focus, world_camera.as_deref(), &q_focus, aspect);
+        material.color = transition.color.to_linear();
+        material.params = vec4(coverage, transition.kind.index(), center.x, center.y);
+        material.extra.y = radius;
+    }
+
+    if transition.timer.finished() {
+        let event = TransitionFinished {
+            kind:     transition.kind,
+            cover:    transition.cover,
+            replaced: false,
+        };
+        commands.trigger_targets(event, entity);
+        commands.entity(entity).despawn();
+    }
+}
+
+pub fn fade_to_black(mut commands: Commands) {
+    commands.transition(Transition::cover(TransitionKind::Fade, BLACK));
+}
+
+pub fn fade_from_black(mut commands: Commands) {
+    commands.transition(Transition::reveal(TransitionKind::Fade, BLACK));
+}
+
+pub fn fade_to_white(mut commands: Commands) {
+    commands.transition(Transition::cover(TransitionKind::Fade, WHITE));
+}
+
+pub fn fade_from_white(mut commands: Commands) {
+    commands.transition(Transition::reveal(TransitionKind::Fade, WHITE));
+}
+
+// Reveals the screen the same way it was last covered
+pub fn fade_from_whatever(mut commands: Commands, overlay: Single<&TransitionOverlay>) {
+    if overlay.coverage <= 0.0 {
+        return;
+    }
+
+    let transition = Transition::reveal(overlay.kind, overlay.color);
+    commands.transition(match overlay.focus {
+        Some(focus) => transition.focus(focus),
+        None => transition,
+    });
+}
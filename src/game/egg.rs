@@ -2,8 +2,13 @@ use bevy::audio::Volume;
 use bevy::prelude::*;
 
 use crate::{
-    RENDER_LAYER_OVERLAY, RENDER_LAYER_SPECIAL, RENDER_LAYER_WORLD, animation::*, audio::*,
-    auto_scaling::AspectRatio, despawn_screen, game::interactions::*, progress::*,
+    RENDER_LAYER_OVERLAY, RENDER_LAYER_SPECIAL, RENDER_LAYER_WORLD, StandardFont,
+    animation::*,
+    audio::*,
+    auto_scaling::AspectRatio,
+    despawn_screen,
+    game::interactions::{prompt::*, special::*, *},
+    progress::*,
 };
 
 use super::*;
@@ -61,6 +66,7 @@ pub fn egg_plugin(app: &mut App) {
         Update,
         (
             move_player,
+            update_egg_prompt,
             get_egg_interactions
                 .pipe(play_interactions)
                 .run_if(just_pressed_interact),
@@ -147,6 +153,8 @@ fn setup_player(mut commands: Commands) {
         SpatialListener::default(),
         children![(
             WorldCamera,
+            // Shake and kicks are in metres rather than pixels
+            effects::CameraEffects::scaled(0.01),
             MeshPickingCamera,
             Camera3d::default(),
             Camera {
@@ -196,10 +204,7 @@ fn setup_world(
         })),
         Transform::from_xyz(0.3, 1.4, 1.525),
         PICKABLE,
-        EntityInteraction::Text(
-            "Through eons of void, these photons birth from fusion, lay to rest in you."
-                .to_string(),
-        ),
+        EntityInteraction::Text("egg.window_glass".to_string()),
     ));
 
     // Star light
@@ -260,7 +265,7 @@ fn initialize_crt_panel(
         commands.entity(entity).insert((
             CrtPanel,
             PICKABLE,
-            EntityInteraction::Text("amogus".to_string()),
+            EntityInteraction::Text("egg.crt_panel".to_string()),
         ));
     }
 }
@@ -372,6 +377,30 @@ fn out_interactables(
     }
 }
 
+// Shows the interact prompt under the crosshair while it hovers an interactable
+fn update_egg_prompt(
+    mut commands: Commands,
+    player: Single<&InteractTarget, (With<Player>, Changed<InteractTarget>)>,
+    q_prompts: Query<Entity, With<InteractPrompt>>,
+    settings: Res<Persistent<Settings>>,
+    font: Res<StandardFont>,
+) {
+    const PROMPT_OFFSET: Vec3 = vec3(0.0, -40.0, Z_EFFECTS);
+    const PROMPT_FONT_SIZE: f32 = 16.0;
+
+    q_prompts.iter().for_each(|prompt| {
+        commands.entity(prompt).despawn();
+    });
+
+    if player.get().is_some() {
+        commands.spawn((
+            OnEggScene,
+            interact_prompt(&settings, &font, PROMPT_FONT_SIZE, PROMPT_OFFSET),
+            RENDER_LAYER_OVERLAY,
+        ));
+    }
+}
+
 use bevy::picking::backend::PointerHits;
 use bevy::picking::pointer::PointerId;
 fn get_egg_interactions(
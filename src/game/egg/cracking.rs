@@ -17,7 +17,8 @@ enum CrackingPhase {
 }
 
 pub fn egg_cracking_plugin(app: &mut App) {
-    app.add_sub_state::<CrackingPhase>();
+    app.add_sub_state::<CrackingPhase>()
+        .add_special_interaction("start_cracking", start_cracking);
 
     app.add_systems(
         OnEnter(GameState::Egg),
@@ -118,6 +119,10 @@ struct CrackingSFX {
 #[derive(Debug, Component)]
 struct ViolenceMusic;
 
+fn start_cracking(In(_args): In<SpecialArgs>, mut commands: Commands) {
+    commands.set_state(EggState::Cracking);
+}
+
 fn setup_cracking_elements(
     mut commands: Commands,
     mut asset_tracker: ResMut<AssetTracker>,
@@ -204,22 +209,20 @@ fn setup_cracking_elements(
             Transform::default(),
             Visibility::Hidden,
             cracking_sfx,
-            SpecialInteraction::new(move |commands: &mut Commands, _entity: Entity| {
-                commands.set_state(EggState::Cracking);
-            }),
         ))
         .id();
 
-    let ami_intro = asset_server.load("sprites/ami_intro.png");
-    asset_tracker.push(ami_intro.clone_weak().untyped());
-    let ami_layout = TextureAtlasLayout::from_grid(uvec2(128, 96), 10, 6, None, None);
-    let ami_layout = asset_server.add(ami_layout);
+    let (ami_animations, ami_sprite) =
+        SpriteAnimations::load(&asset_server, "sprites/ami_intro.anim.ron");
+    asset_tracker.push(ami_animations.clone_weak().untyped());
 
     commands.spawn((
         ChildOf(cracking_root),
         Name::new("CRT_Sprite"),
-        Sprite::from_atlas_image(ami_intro, ami_layout.into()),
-        SpriteAnimation::new(0, 58, 12).looping(),
+        ami_sprite,
+        ami_animations,
+        SpriteAnimator::new("intro").with_state("intro", AnimatorState::new("intro")),
+        SpriteAnimation::set_frame(0),
         Transform::default(),
         Visibility::default(),
         RENDER_LAYER_SPECIAL,
@@ -269,18 +272,19 @@ fn setup_cracking_elements(
         MeshMaterial3d(material.clone_weak()),
         crack_materials,
         PICKABLE,
-        EntityInteraction::Special(cracking_root),
+        EntityInteraction::Special("start_cracking".into()),
     ));
 
     let violence_music = asset_server.load("audio/music/catching_air.ogg");
     asset_tracker.push(violence_music.clone_weak().untyped());
     commands.spawn((
+        Music,
         ViolenceMusic,
         ChildOf(cracking_root),
         Name::new("Violence Music"),
         AudioPlayer::new(violence_music),
         PlaybackSettings::LOOP
-            .with_volume(Volume::Linear(settings.music_vol))
+            .with_volume(Music::volume(&settings))
             .muted(),
     ));
 
@@ -298,16 +302,21 @@ fn setup_cracking_elements(
         Music,
         AmiIntroPlayer,
         Name::new("Ami Intro Player"),
+        AudioGain(1.0 / 6.0),
         AudioPlayer::new(ami_intro),
         PlaybackSettings::LOOP
             .paused()
-            .with_volume(Volume::Linear(settings.music_vol / 6.0))
+            .with_volume(Music::volume(&settings) * Volume::Linear(1.0 / 6.0))
             .with_spatial(true),
         crack_transform,
     ));
 }
 
-fn setup_cracking_animations(mut commands: Commands, asset_server: Res<AssetServer>) {
+fn setup_cracking_animations(
+    mut commands: Commands,
+    mut asset_tracker: ResMut<AssetTracker>,
+    asset_server: Res<AssetServer>,
+) {
     // Default animation duration: 1.0 second
     // const MEDIUM_DURATION: f32 = 0.5;
     const FAST_DURATION: f32 = 0.125;
@@ -315,19 +324,14 @@ fn setup_cracking_animations(mut commands: Commands, asset_server: Res<AssetServ
     const CCW90_ROTATION: Quat = Quat::from_array([0.0, 0.0, FRAC_1_SQRT_2, FRAC_1_SQRT_2]);
     const CW90_ROTATION: Quat = Quat::from_array([0.0, 0.0, -FRAC_1_SQRT_2, FRAC_1_SQRT_2]);
 
-    let punch_image: Handle<Image> = asset_server.load("sprites/punch.png");
-    let punch_layout = TextureAtlasLayout::from_grid(UVec2::splat(256), 2, 1, None, None);
-    let punch_layout_handle = asset_server.add(punch_layout);
+    let (punch_animations, punch_sprite) =
+        SpriteAnimations::load(&asset_server, "sprites/punch.anim.ron");
+    asset_tracker.push(punch_animations.clone_weak().untyped());
 
     {
         let punch_lr_sprite = Sprite {
-            image: punch_image.clone(),
-            texture_atlas: Some(TextureAtlas {
-                layout: punch_layout_handle.clone(),
-                index:  0,
-            }),
             custom_size: Some(Vec2::splat(720.0)),
-            ..default()
+            ..punch_sprite.clone()
         };
 
         let punch_ll_sprite = Sprite {
@@ -374,6 +378,7 @@ fn setup_cracking_animations(mut commands: Commands, asset_server: Res<AssetServ
             commands
                 .spawn((
                     punch_lr_sprite,
+                    punch_animations.clone(),
                     SpriteAnimation::set_frame(0),
                     Transform::from_xyz(256.0, 0.0, Z_SPRITES),
                     RENDER_LAYER_OVERLAY,
@@ -382,6 +387,7 @@ fn setup_cracking_animations(mut commands: Commands, asset_server: Res<AssetServ
             commands
                 .spawn((
                     punch_ll_sprite,
+                    punch_animations.clone(),
                     SpriteAnimation::set_frame(0),
                     Transform::from_xyz(-256.0, 0.0, Z_SPRITES),
                     RENDER_LAYER_OVERLAY,
@@ -427,13 +433,8 @@ fn setup_cracking_animations(mut commands: Commands, asset_server: Res<AssetServ
             .id();
 
         let punch_ur_sprite = Sprite {
-            image: punch_image.clone(),
-            texture_atlas: Some(TextureAtlas {
-                layout: punch_layout_handle.clone(),
-                index:  0,
-            }),
             custom_size: Some(Vec2::splat(720.0)),
-            ..default()
+            ..punch_sprite
         };
 
         let punch_ul_sprite = Sprite {
@@ -500,6 +501,7 @@ fn setup_cracking_animations(mut commands: Commands, asset_server: Res<AssetServ
             commands
                 .spawn((
                     punch_ur_sprite,
+                    punch_animations.clone(),
                     SpriteAnimation::set_frame(0),
                     AnimationTarget {
                         id:     punch_ur_id,
@@ -512,6 +514,7 @@ fn setup_cracking_animations(mut commands: Commands, asset_server: Res<AssetServ
             commands
                 .spawn((
                     punch_ul_sprite,
+                    punch_animations,
                     SpriteAnimation::set_frame(0),
                     AnimationTarget {
                         id:     punch_ul_id,
@@ -552,7 +555,6 @@ fn setup_cracking_animations(mut commands: Commands, asset_server: Res<AssetServ
             name: &'static str,
 
             path:        &'static str,
-            layout:      TextureAtlasLayout,
             flip_x:      bool,
             custom_size: Option<Vec2>,
 
@@ -561,19 +563,10 @@ fn setup_cracking_animations(mut commands: Commands, asset_server: Res<AssetServ
             translation_out: Vec3,
         }
 
-        const PADDING: UVec2 = UVec2::splat(16);
-
         let pre_animations = [
             PreAnimation {
                 name:            "machgun",
-                path:            "sprites/machgun.png",
-                layout:          TextureAtlasLayout::from_grid(
-                    uvec2(256, 132),
-                    4,
-                    1,
-                    Some(PADDING),
-                    None,
-                ),
+                path:            "sprites/machgun.anim.ron",
                 flip_x:          false,
                 custom_size:     Some(vec2(512.0, 264.0)),
                 rotation:        Quat::default(),
@@ -582,14 +575,7 @@ fn setup_cracking_animations(mut commands: Commands, asset_server: Res<AssetServ
             },
             PreAnimation {
                 name:            "shotgun",
-                path:            "sprites/shotgun.png",
-                layout:          TextureAtlasLayout::from_grid(
-                    uvec2(138, 156),
-                    5,
-                    3,
-                    Some(PADDING),
-                    None,
-                ),
+                path:            "sprites/shotgun.anim.ron",
                 flip_x:          false,
                 custom_size:     Some(vec2(276.0, 312.0)),
                 rotation:        Quat::default(),
@@ -598,14 +584,7 @@ fn setup_cracking_animations(mut commands: Commands, asset_server: Res<AssetServ
             },
             PreAnimation {
                 name:            "pistol1",
-                path:            "sprites/pistol1.png",
-                layout:          TextureAtlasLayout::from_grid(
-                    uvec2(152, 152),
-                    4,
-                    1,
-                    Some(PADDING),
-                    None,
-                ),
+                path:            "sprites/pistol1.anim.ron",
                 flip_x:          false,
                 custom_size:     Some(vec2(304.0, 304.0)),
                 rotation:        CCW90_ROTATION,
@@ -614,14 +593,7 @@ fn setup_cracking_animations(mut commands: Commands, asset_server: Res<AssetServ
             },
             PreAnimation {
                 name:            "pistol2",
-                path:            "sprites/pistol2.png",
-                layout:          TextureAtlasLayout::from_grid(
-                    uvec2(112, 132),
-                    4,
-                    1,
-                    Some(PADDING),
-                    None,
-                ),
+                path:            "sprites/pistol2.anim.ron",
                 flip_x:          true,
                 custom_size:     Some(vec2(224.0, 264.0)),
                 rotation:        CW90_ROTATION,
@@ -643,7 +615,6 @@ fn setup_cracking_animations(mut commands: Commands, asset_server: Res<AssetServ
                 let PreAnimation {
                     name,
                     path,
-                    layout,
                     flip_x,
                     custom_size,
                     rotation,
@@ -654,18 +625,17 @@ fn setup_cracking_animations(mut commands: Commands, asset_server: Res<AssetServ
                 let name = Name::new(name);
                 let target_id = AnimationTargetId::from_name(&name);
 
+                let (animations, sprite) = SpriteAnimations::load(&asset_server, path);
+                asset_tracker.push(animations.clone_weak().untyped());
+
                 let animation_entity = commands
                     .spawn((
                         Sprite {
-                            image: asset_server.load(path),
-                            texture_atlas: Some(TextureAtlas {
-                                layout: asset_server.add(layout),
-                                index:  0,
-                            }),
                             flip_x,
                             custom_size,
-                            ..default()
+                            ..sprite
                         },
+                        animations,
                         SpriteAnimation::set_frame(0),
                         AnimationTarget {
                             id:     target_id,
@@ -877,10 +847,14 @@ fn advance_crack_phase(
     }
 }
 
+// Frame event of punches and gun shots that damages the crack
+const PUNCH_IMPACT: &str = "impact";
+
 fn punch(
     user_input: Res<UserInput>,
     q_elements: Query<&CrackingAnimationInfo, Without<Player>>,
-    mut q_sprite_animations: Query<&mut SpriteAnimation>,
+    mut q_sprite_animations: Query<(&mut SpriteAnimation, &SpriteAnimations)>,
+    sets: Res<Assets<SpriteAnimationSet>>,
     mut right_left: Local<bool>,
 ) {
     if let Some(info) = q_elements
@@ -888,9 +862,9 @@ fn punch(
         .find(|info| matches!(info.id, CrackingAnimationId::PunchLower))
     {
         let current_fist = info.parts[*right_left as usize];
-        if let Ok(mut animation) = q_sprite_animations.get_mut(current_fist) {
+        if let Ok((mut animation, animations)) = q_sprite_animations.get_mut(current_fist) {
             match user_input.interact {
-                KeyState::Press => *animation = SpriteAnimation::set_frame(1),
+                KeyState::Press => *animation = animations.clip(&sets, "punch"),
                 KeyState::Release => {
                     *animation = SpriteAnimation::set_frame(0);
                     *right_left ^= true;
@@ -904,7 +878,8 @@ fn punch(
 fn fast_punch(
     user_input: Res<UserInput>,
     q_elements: Query<&CrackingAnimationInfo, Without<Player>>,
-    mut q_sprite_animations: Query<&mut SpriteAnimation>,
+    mut q_sprite_animations: Query<(&mut SpriteAnimation, &SpriteAnimations)>,
+    sets: Res<Assets<SpriteAnimationSet>>,
     mut right_left: Local<bool>,
 ) {
     const FLIP: [bool; 2] = [false, true];
@@ -914,16 +889,16 @@ fn fast_punch(
         .find(|info| info.id == CrackingAnimationId::PunchLower)
     {
         info.parts.iter().zip(FLIP).for_each(|(&entity, flip)| {
-            if let Ok(mut animation) = q_sprite_animations.get_mut(entity) {
+            if let Ok((mut animation, animations)) = q_sprite_animations.get_mut(entity) {
                 match user_input.interact {
                     KeyState::Press => {
                         let toggle = match *right_left ^ flip {
                             true => 1.0,
                             false => 0.0,
                         };
-                        *animation = SpriteAnimation::new(0, 1, 12)
-                            .with_delay(0.083 * toggle)
-                            .looping();
+                        *animation = animations
+                            .clip(&sets, "fast_punch")
+                            .with_delay(0.083 * toggle);
                     }
                     KeyState::Release => {
                         *animation = SpriteAnimation::set_frame(0);
@@ -939,7 +914,8 @@ fn fast_punch(
 fn quad_punch(
     user_input: Res<UserInput>,
     q_elements: Query<&CrackingAnimationInfo, Without<Player>>,
-    mut q_sprite_animations: Query<&mut SpriteAnimation>,
+    mut q_sprite_animations: Query<(&mut SpriteAnimation, &SpriteAnimations)>,
+    sets: Res<Assets<SpriteAnimationSet>>,
 ) {
     q_elements
         .iter()
@@ -953,12 +929,12 @@ fn quad_punch(
         .flatten()
         .enumerate()
         .for_each(|(i, &entity)| {
-            if let Ok(mut animation) = q_sprite_animations.get_mut(entity) {
+            if let Ok((mut animation, animations)) = q_sprite_animations.get_mut(entity) {
                 match user_input.interact {
                     KeyState::Press => {
-                        *animation = SpriteAnimation::new(0, 1, 16)
+                        *animation = animations
+                            .clip(&sets, "quad_punch")
                             .with_delay(0.016 * i as f32)
-                            .looping()
                     }
                     KeyState::Release => *animation = SpriteAnimation::set_frame(0),
                     _ => (),
@@ -970,38 +946,26 @@ fn quad_punch(
 fn violence(
     user_input: Res<UserInput>,
     mut q_elements: Query<(&CrackingAnimationInfo, &mut AnimationPlayer)>,
-    mut q_sprite_animations: Query<&mut SpriteAnimation>,
+    mut q_sprite_animations: Query<(&mut SpriteAnimation, &SpriteAnimations)>,
+    sets: Res<Assets<SpriteAnimationSet>>,
 ) {
-    struct SpriteInfo {
-        len: usize,
-        fps: u8,
-    }
-
-    const GUNS_SPRITE_ANIMATION_INFO: [SpriteInfo; 4] = [
-        SpriteInfo { len: 4, fps: 60 },
-        SpriteInfo { len: 15, fps: 24 },
-        SpriteInfo { len: 4, fps: 24 },
-        SpriteInfo { len: 4, fps: 12 },
-    ];
-
     q_elements
         .iter_mut()
         .for_each(|(info, mut animator)| match info.id {
             CrackingAnimationId::Guns => match user_input.interact {
                 KeyState::Press => {
                     animator.stop_all().play(info.in_node);
-                    info.parts.iter().zip(GUNS_SPRITE_ANIMATION_INFO).for_each(
-                        |(&entity, SpriteInfo { len, fps })| {
-                            if let Ok(mut animation) = q_sprite_animations.get_mut(entity) {
-                                *animation = SpriteAnimation::new(0, len - 1, fps).looping();
-                            }
-                        },
-                    );
+                    info.parts.iter().for_each(|&entity| {
+                        if let Ok((mut animation, animations)) = q_sprite_animations.get_mut(entity)
+                        {
+                            *animation = animations.clip(&sets, "fire");
+                        }
+                    });
                 }
                 KeyState::Release => {
                     animator.stop_all().play(info.out_node);
                     info.parts.iter().for_each(|&entity| {
-                        if let Ok(mut animation) = q_sprite_animations.get_mut(entity) {
+                        if let Ok((mut animation, _)) = q_sprite_animations.get_mut(entity) {
                             *animation = SpriteAnimation::set_frame(0);
                         }
                     });
@@ -1038,7 +1002,7 @@ fn play_sfx(
             commands.spawn((
                 Sound,
                 AudioPlayer::new(punch_sfx.clone_weak()),
-                PlaybackSettings::DESPAWN.with_volume(Volume::Linear(settings.sound_vol)),
+                PlaybackSettings::DESPAWN.with_volume(Sound::volume(&settings)),
             ));
 
             *cursor += 1;
@@ -1050,14 +1014,14 @@ fn play_sfx(
             commands.spawn((
                 Sound,
                 AudioPlayer::new(sfx.fast_punch.clone_weak()),
-                PlaybackSettings::LOOP.with_volume(Volume::Linear(settings.sound_vol)),
+                PlaybackSettings::LOOP.with_volume(Sound::volume(&settings)),
             ));
         }
         CrackingPhase::QuadPunch => {
             commands.spawn((
                 Sound,
                 AudioPlayer::new(sfx.quad_punch.clone_weak()),
-                PlaybackSettings::LOOP.with_volume(Volume::Linear(settings.sound_vol)),
+                PlaybackSettings::LOOP.with_volume(Sound::volume(&settings)),
             ));
         }
         CrackingPhase::Violence => {
@@ -1065,7 +1029,7 @@ fn play_sfx(
             commands.spawn((
                 Sound,
                 AudioPlayer::new(sfx.violence.clone_weak()),
-                PlaybackSettings::LOOP.with_volume(Volume::Linear(settings.sound_vol)),
+                PlaybackSettings::LOOP.with_volume(Sound::volume(&settings)),
             ));
         }
         _ => (),
@@ -1121,13 +1085,14 @@ fn cracking_animations_out(
 }
 
 fn update_crack(
+    mut commands: Commands,
     q_animation_info: Query<&CrackingAnimationInfo>,
     crack: Single<(
         &mut CrackHealth,
         &mut MeshMaterial3d<StandardMaterial>,
         &CrackMaterials,
     )>,
-    mut e_reader: EventReader<SpriteAnimationFinished>,
+    mut e_reader: EventReader<SpriteAnimationEvent>,
     mut cracking_animations: Local<Vec<Entity>>,
     mut progress: ResMut<Progress>,
 ) {
@@ -1146,15 +1111,21 @@ fn update_crack(
 
     let damage = e_reader
         .read()
-        .filter(|event| cracking_animations.contains(&event.entity))
+        .filter(|event| event.name == PUNCH_IMPACT && cracking_animations.contains(&event.entity))
         .count() as u8;
     e_reader.clear();
 
     crack_health.0 = crack_health.saturating_sub(damage);
 
+    if damage > 0 {
+        commands.trigger(effects::CameraImpulse::Trauma(0.2 * damage as f32));
+    }
+
     let new_damage_level = crack_health.damage_level();
 
     if new_damage_level != old_damage_level {
+        commands.trigger(effects::CameraImpulse::HitStop(0.08));
+        commands.trigger(effects::CameraImpulse::Kick(vec2(0.0, -6.0)));
         if let Some(new_material) = crack_materials.get(new_damage_level) {
             current_material.0 = new_material.clone_weak();
         }
@@ -1167,14 +1138,12 @@ fn update_crack(
 
 fn update_intro_player(
     crack_health: Single<&CrackHealth, Changed<CrackHealth>>,
-    mut ami_intro: Single<&mut SpatialAudioSink, With<AmiIntroPlayer>>,
-    settings: Res<Persistent<Settings>>,
+    mut ami_intro_gain: Single<&mut AudioGain, With<AmiIntroPlayer>>,
     mut old_damage_level: Local<usize>,
 ) {
     let new_damage_level = crack_health.damage_level();
     if *old_damage_level != new_damage_level {
         *old_damage_level = new_damage_level;
-        let new_volume = Volume::Linear(settings.music_vol * (new_damage_level + 1) as f32 / 6.0);
-        ami_intro.set_volume(new_volume);
+        ami_intro_gain.0 = (new_damage_level + 1) as f32 / 6.0;
     }
 }
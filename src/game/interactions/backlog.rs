@@ -0,0 +1,248 @@
+use std::{collections::VecDeque, time::Duration};
+
+use bevy::{input::mouse::AccumulatedMouseScroll, prelude::*};
+use serde::{Deserialize, Serialize};
+
+use super::*;
+use crate::{
+    StandardFont, despawn_screen,
+    progress::{Progress, TimePlayedStart},
+};
+
+pub fn backlog_plugin(app: &mut App) {
+    app.add_systems(
+        Update,
+        (
+            record_backlog_lines.run_if(on_event::<BacklogLine>),
+            (open_backlog, pause_game).run_if(
+                in_state(InGame)
+                    .and(pressed_backlog_key)
+                    .and(not(any_with_component::<BacklogPanel>)),
+            ),
+            (
+                scroll_backlog,
+                (close_backlog, unpause_game).run_if(pressed_backlog_key.or(just_pressed_escape)),
+            )
+                .run_if(any_with_component::<BacklogPanel>),
+        ),
+    )
+    .add_systems(OnExit(InGame), despawn_screen::<BacklogPanel>)
+    .add_event::<BacklogLine>()
+    .register_type::<Backlog>()
+    .register_type::<BacklogEntry>();
+}
+
+// Amount of lines kept per save slot, oldest lines are dropped first
+const BACKLOG_CAPACITY: usize = 200;
+
+// Sent whenever a line of text is shown through an interaction
+#[derive(Debug, Clone, Event)]
+pub struct BacklogLine {
+    pub speaker: Character,
+    pub text:    String,
+}
+
+impl BacklogLine {
+    pub fn new(speaker: Character, text: impl Into<String>) -> Self {
+        BacklogLine {
+            speaker,
+            text: text.into(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Reflect, Serialize, Deserialize)]
+pub struct BacklogEntry {
+    pub speaker:     Character,
+    pub text:        String,
+    // Time played on the current save slot when the line was shown
+    pub time_played: Duration,
+}
+
+#[derive(Debug, Clone, Default, Deref, DerefMut, Reflect, Serialize, Deserialize)]
+#[reflect(Default, Serialize, Deserialize)]
+pub struct Backlog(VecDeque<BacklogEntry>);
+
+impl Backlog {
+    pub fn push(&mut self, entry: BacklogEntry) {
+        if self.0.len() == BACKLOG_CAPACITY {
+            self.0.pop_front();
+        }
+        self.0.push_back(entry);
+    }
+}
+
+fn record_backlog_lines(
+    mut e_reader: EventReader<BacklogLine>,
+    mut progress: ResMut<Progress>,
+    time_played_start: Res<TimePlayedStart>,
+) {
+    let time_played = progress.time_played + time_played_start.elapsed();
+
+    e_reader.read().for_each(|BacklogLine { speaker, text }| {
+        if text.is_empty() {
+            return;
+        }
+        progress.backlog.push(BacklogEntry {
+            speaker: *speaker,
+            text: text.clone(),
+            time_played,
+        });
+    });
+}
+
+fn pressed_backlog_key(
+    key_input: Res<ButtonInput<KeyCode>>,
+    settings: Res<Persistent<Settings>>,
+) -> bool {
+    key_input.just_pressed(settings.backlog)
+}
+
+#[derive(Debug, Component)]
+pub struct BacklogPanel;
+
+#[derive(Debug, Component)]
+struct BacklogScroll;
+
+fn open_backlog(
+    mut commands: Commands,
+    progress: Res<Progress>,
+    font: Res<StandardFont>,
+    locale: Res<Locale>,
+) {
+    let text_font = TextFont {
+        font: font.clone_weak(),
+        font_size: 16.0,
+        font_smoothing: bevy::text::FontSmoothing::None,
+        ..default()
+    };
+
+    let backlog_panel = commands
+        .spawn((
+            BacklogPanel,
+            Name::new("Backlog"),
+            Node {
+                position_type: PositionType::Absolute,
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                padding: UiRect::axes(Val::Percent(10.0), Val::Percent(5.0)),
+                flex_direction: FlexDirection::Column,
+                ..default()
+            },
+            BackgroundColor(Color::linear_rgba(0.0, 0.0, 0.0, 0.85)),
+            GlobalZIndex(i32::MAX),
+        ))
+        .id();
+
+    commands.spawn((
+        ChildOf(backlog_panel),
+        LocalizedText::new("backlog.title"),
+        TextFont {
+            font_size: 32.0,
+            ..text_font.clone()
+        },
+        TextColor(WHITE.into()),
+        Node {
+            margin: UiRect::bottom(Val::Px(16.0)),
+            ..default()
+        },
+    ));
+
+    let backlog_scroll = commands
+        .spawn((
+            BacklogScroll,
+            ChildOf(backlog_panel),
+            Node {
+                width: Val::Percent(100.0),
+                flex_grow: 1.0,
+                flex_direction: FlexDirection::Column,
+                overflow: Overflow::scroll_y(),
+                ..default()
+            },
+            // Clamped to the bottom of the backlog during layout
+            ScrollPosition {
+                offset_x: 0.0,
+                offset_y: f32::MAX,
+            },
+        ))
+        .id();
+
+    if progress.backlog.is_empty() {
+        commands.spawn((
+            ChildOf(backlog_scroll),
+            LocalizedText::new("backlog.empty"),
+            text_font,
+            TextColor(GRAY.into()),
+        ));
+        return;
+    }
+
+    progress.backlog.iter().for_each(|entry| {
+        let prefix = match entry.speaker {
+            Character::None => String::new(),
+            speaker => format!("{}: ", locale.get(speaker.name_key())),
+        };
+
+        commands.spawn((
+            ChildOf(backlog_scroll),
+            Node {
+                margin: UiRect::vertical(Val::Px(4.0)),
+                column_gap: Val::Px(16.0),
+                ..default()
+            },
+            children![
+                (
+                    Text::new(format_time_played(entry.time_played)),
+                    text_font.clone(),
+                    TextColor(GRAY.into()),
+                ),
+                (
+                    Text::new(format!("{prefix}{}", entry.text)),
+                    text_font.clone(),
+                    TextColor(WHITE.into()),
+                ),
+            ],
+        ));
+    });
+}
+
+fn close_backlog(mut commands: Commands, backlog_panel: Single<Entity, With<BacklogPanel>>) {
+    commands.entity(backlog_panel.into_inner()).despawn();
+}
+
+fn scroll_backlog(
+    mut scroll_position: Single<&mut ScrollPosition, With<BacklogScroll>>,
+    mouse_scroll: Res<AccumulatedMouseScroll>,
+    key_input: Res<ButtonInput<KeyCode>>,
+    settings: Res<Persistent<Settings>>,
+    time: Res<Time>,
+) {
+    use bevy::input::mouse::MouseScrollUnit;
+
+    const LINE_HEIGHT: f32 = 24.0;
+    const KEY_SCROLL_SPEED: f32 = 512.0; // pixels per second
+
+    let mut delta = match mouse_scroll.unit {
+        MouseScrollUnit::Line => -mouse_scroll.delta.y * LINE_HEIGHT,
+        MouseScrollUnit::Pixel => -mouse_scroll.delta.y,
+    };
+
+    if key_input.pressed(settings.up) {
+        delta -= KEY_SCROLL_SPEED * time.delta_secs();
+    }
+    if key_input.pressed(settings.down) {
+        delta += KEY_SCROLL_SPEED * time.delta_secs();
+    }
+
+    if delta != 0.0 {
+        // Layout clamps the offset to the scrollable area
+        scroll_position.offset_y = (scroll_position.offset_y + delta).max(0.0);
+    }
+}
+
+fn format_time_played(time_played: Duration) -> String {
+    let seconds = time_played.as_secs();
+    let minutes = seconds / 60;
+    let hours = minutes / 60;
+    format!("{:02}:{:02}:{:02}", hours, minutes % 60, seconds % 60)
+}
@@ -4,6 +4,7 @@ use std::hash::{Hash, Hasher};
 use bevy::{
     animation::{AnimationTarget, AnimationTargetId, animated_field},
     asset::LoadState,
+    audio::Volume,
     prelude::*,
 };
 use bevy_text_animation::TextSimpleAnimator;
@@ -11,8 +12,11 @@ use serde::{Deserialize, Serialize};
 
 use super::*;
 use crate::{
-    Blob, EnumMap, RENDER_LAYER_OVERLAY, WINDOW_WIDTH, animation::SpriteAnimation,
-    game::effects::*, progress::Progress,
+    Blob, EnumMap, RENDER_LAYER_OVERLAY, WINDOW_WIDTH,
+    animation::{SpriteAnimation, SpriteImages},
+    audio::*,
+    game::effects::*,
+    progress::Progress,
 };
 
 #[derive(SubStates, Clone, PartialEq, Eq, Hash, Debug, Default)]
@@ -41,11 +45,23 @@ pub fn dialogue_plugin(app: &mut App) {
             Update,
             wait_for_loaded_and_bars.run_if(in_state(DialogueState::Loading)),
         )
+        .add_systems(
+            OnEnter(InteractionState::Dialogue),
+            (reset_dialogue_modes, duck_music::<true>),
+        )
+        .add_systems(OnExit(InteractionState::Dialogue), duck_music::<false>)
         .add_systems(OnEnter(DialogueState::Playing), play_dialogue)
         .add_systems(
             Update,
-            advance_dialogue
-                .run_if(in_state(DialogueState::Playing).and(on_event::<InteractionAdvance>)),
+            (
+                (toggle_dialogue_modes, auto_advance_dialogue)
+                    .chain()
+                    .run_if(not(any_with_component::<BacklogPanel>)),
+                update_dialogue_mode_indicator.run_if(resource_changed::<DialogueModes>),
+                advance_dialogue.run_if(on_event::<InteractionAdvance>),
+            )
+                .chain()
+                .run_if(in_state(DialogueState::Playing)),
         )
         .add_systems(
             OnEnter(DialogueState::Ending),
@@ -57,6 +73,7 @@ pub fn dialogue_plugin(app: &mut App) {
                 .run_if(in_state(DialogueState::Ending).and(on_event::<CinematicBarsOut>)),
         )
         .add_sub_state::<DialogueState>()
+        .init_resource::<DialogueModes>()
         .init_resource::<DialoguePreload>()
         .init_resource::<DialogueStorage>()
         .register_type::<DialogueId>()
@@ -116,85 +133,211 @@ fn wait_for_loaded_and_bars(
 }
 
 fn play_dialogue(
+    mut commands: Commands,
+    current_id: Res<DialogueCurrentId>,
     current_dialogues: Single<(&Dialogue, &mut AnimationPlayer), With<DialogueCurrent>>,
     interaction_prefix: Single<&mut TextSimpleAnimator, With<InteractionPrefix>>,
     interaction_text: Single<
         &mut TextSimpleAnimator,
         (With<InteractionText>, Without<InteractionPrefix>),
     >,
+    settings: Res<Persistent<Settings>>,
+    mut progress: ResMut<Progress>,
+    mut e_writer: EventWriter<BacklogLine>,
+    locale: Res<Locale>,
 ) {
     let (dialogue, mut element_animator) = current_dialogues.into_inner();
 
     element_animator.play(dialogue.nodes[0]);
 
-    let TextAnimatorInfo { text, speed, delay } = &dialogue.texts[0];
-
-    let speaker = dialogue.speakers[0];
-    let mut prefix_animator = match speaker {
-        Character::None => TextSimpleAnimator::default(),
-        _ => TextSimpleAnimator::new(&format!("{speaker}: "), f32::MAX),
-    };
-    let mut text_animator = TextSimpleAnimator::new(text, *speed);
-
-    if let Some(seconds) = delay {
-        prefix_animator = prefix_animator.with_wait_before(*seconds);
-        text_animator = text_animator.with_wait_before(*seconds);
-    }
-
+    let (prefix_animator, text_animator) = dialogue.line_animators(0, &locale);
     *interaction_prefix.into_inner() = prefix_animator;
     *interaction_text.into_inner() = text_animator;
+
+    let seen = progress.see_dialogue_line(current_id.0, 0);
+    commands.insert_resource(DialogueCursor::new(seen, settings.reading_delay));
+
+    e_writer.write(dialogue.backlog_line(0, &locale));
 }
 
 #[allow(clippy::type_complexity)]
 fn advance_dialogue(
+    current_id: Res<DialogueCurrentId>,
     current_dialogues: Single<(&Dialogue, &mut AnimationPlayer), With<DialogueCurrent>>,
     interaction_prefix: Single<&mut TextSimpleAnimator, With<InteractionPrefix>>,
     interaction_text: Single<
         (&mut Text, &mut TextSimpleAnimator),
         (With<InteractionText>, Without<InteractionPrefix>),
     >,
+    mut cursor: ResMut<DialogueCursor>,
+    mut progress: ResMut<Progress>,
     mut dialogue_state: ResMut<NextState<DialogueState>>,
-    mut line_index: Local<usize>,
+    mut e_writer: EventWriter<BacklogLine>,
+    locale: Res<Locale>,
 ) {
-    *line_index += 1;
-
     let (dialogue, mut element_animator) = current_dialogues.into_inner();
     let (mut text, mut text_animator) = interaction_text.into_inner();
 
-    if *line_index == dialogue.nodes.len() {
-        // Dialogue is finished
-        *line_index = 0;
-        text.clear();
-        dialogue_state.set(DialogueState::Ending);
+    // Finish any pending actions before moving onto the next line
+    if !element_animator.all_finished() {
+        fast_forward(&mut element_animator);
         return;
     }
 
-    if !element_animator.all_finished() {
-        element_animator.adjust_speeds(256.0);
+    cursor.line += 1;
+
+    if cursor.line == dialogue.nodes.len() {
+        // Dialogue is finished
+        text.clear();
+        dialogue_state.set(DialogueState::Ending);
         return;
     }
 
-    // info!("Playing animations index: {}", *line_index);
+    // info!("Playing animations index: {}", cursor.line);
     element_animator
         .stop_all()
-        .play(dialogue.nodes[*line_index]);
+        .play(dialogue.nodes[cursor.line]);
+
+    let (prefix_animator, new_text_animator) = dialogue.line_animators(cursor.line, &locale);
+    *interaction_prefix.into_inner() = prefix_animator;
+    *text_animator = new_text_animator;
 
-    let TextAnimatorInfo { text, speed, delay } = &dialogue.texts[*line_index];
+    cursor.seen = progress.see_dialogue_line(current_id.0, cursor.line);
+    cursor.reading_timer.reset();
 
-    let speaker = dialogue.speakers[*line_index];
-    let mut prefix_animator = match speaker {
-        Character::None => TextSimpleAnimator::default(),
-        _ => TextSimpleAnimator::new(&format!("{speaker}: "), f32::MAX),
-    };
-    let mut new_text_animator = TextSimpleAnimator::new(text, *speed);
+    e_writer.write(dialogue.backlog_line(cursor.line, &locale));
+}
+
+const FAST_FORWARD_SPEED: f32 = 256.0;
+
+fn fast_forward(animator: &mut AnimationPlayer) {
+    animator
+        .playing_animations_mut()
+        .for_each(|(_node, animation)| {
+            animation.set_speed(FAST_FORWARD_SPEED);
+        });
+}
+
+// Tracks the line currently played in the current Dialogue
+#[derive(Debug, Resource)]
+struct DialogueCursor {
+    line:          usize,
+    // Whether the line had been seen on this save slot before it was shown
+    seen:          bool,
+    reading_timer: Timer,
+}
 
-    if let Some(seconds) = delay {
-        prefix_animator = prefix_animator.with_wait_before(*seconds);
-        new_text_animator = new_text_animator.with_wait_before(*seconds);
+impl DialogueCursor {
+    fn new(seen: bool, reading_delay: f32) -> Self {
+        DialogueCursor {
+            line: 0,
+            seen,
+            reading_timer: Timer::from_seconds(reading_delay, TimerMode::Once),
+        }
     }
+}
 
-    *interaction_prefix.into_inner() = prefix_animator;
-    *text_animator = new_text_animator;
+#[derive(Debug, Default, Resource)]
+pub struct DialogueModes {
+    pub auto: bool,
+    pub skip: bool,
+}
+
+fn reset_dialogue_modes(mut commands: Commands, settings: Res<Persistent<Settings>>) {
+    commands.insert_resource(DialogueModes {
+        auto: settings.auto_advance,
+        skip: settings.skip_seen,
+    });
+}
+
+fn toggle_dialogue_modes(
+    key_input: Res<ButtonInput<KeyCode>>,
+    settings: Res<Persistent<Settings>>,
+    mut modes: ResMut<DialogueModes>,
+) {
+    if key_input.just_pressed(settings.auto) {
+        modes.auto ^= true;
+    }
+    if key_input.just_pressed(settings.skip) {
+        modes.skip ^= true;
+    }
+}
+
+#[allow(clippy::type_complexity)]
+fn auto_advance_dialogue(
+    current_dialogue: Single<&mut AnimationPlayer, With<DialogueCurrent>>,
+    interaction_text: Single<
+        (&mut Text, &mut TextSimpleAnimator),
+        (With<InteractionText>, Without<InteractionPrefix>),
+    >,
+    mut modes: ResMut<DialogueModes>,
+    mut cursor: ResMut<DialogueCursor>,
+    mut e_writer: EventWriter<InteractionAdvance>,
+    time: Res<Time>,
+) {
+    let mut element_animator = current_dialogue.into_inner();
+    let (mut text, mut text_animator) = interaction_text.into_inner();
+    let text_finished = !(text_animator.is_playing() || text_animator.is_waiting());
+
+    if modes.skip && !cursor.seen {
+        // Stop skipping once an unseen line is reached
+        modes.skip = false;
+    }
+
+    if modes.skip {
+        if !text_finished {
+            text.0 = text_animator.text.clone();
+            text_animator.stop();
+        }
+
+        match element_animator.all_finished() {
+            true => {
+                e_writer.write(InteractionAdvance);
+            }
+            false => fast_forward(&mut element_animator),
+        }
+        return;
+    }
+
+    if modes.auto
+        && text_finished
+        && element_animator.all_finished()
+        && cursor.reading_timer.tick(time.delta()).finished()
+    {
+        e_writer.write(InteractionAdvance);
+    }
+}
+
+#[derive(Debug, Component)]
+struct DialogueModeIndicator;
+
+pub fn dialogue_mode_indicator() -> impl Bundle {
+    (
+        DialogueModeIndicator,
+        Node {
+            position_type: PositionType::Absolute,
+            right: Val::Percent(2.0),
+            ..default()
+        },
+        Text::new(""),
+        TextFont {
+            font_size: 16.0,
+            ..default()
+        },
+        TextColor(GRAY.into()),
+    )
+}
+
+fn update_dialogue_mode_indicator(
+    modes: Res<DialogueModes>,
+    mut indicator: Single<&mut Text, With<DialogueModeIndicator>>,
+    locale: Res<Locale>,
+) {
+    indicator.0 = match (modes.auto, modes.skip) {
+        (_, true) => locale.get("dialogue.mode.skip").to_string(),
+        (true, false) => locale.get("dialogue.mode.auto").to_string(),
+        (false, false) => String::new(),
+    };
 }
 
 fn post_dialogue(
@@ -205,7 +348,7 @@ fn post_dialogue(
     match current_dialogue.0 {
         DialogueId::UckoIntro => {
             commands.set_state(GameState::Bones);
-            progress.position = vec2(1280.0, 128.0);
+            progress.set_spawn("mountains", "bones_return");
         }
         DialogueId::WizuckoWin => {
             commands.set_state(DialogueState::Loading);
@@ -217,13 +360,20 @@ fn post_dialogue(
 
 fn conclude_dialogue(
     mut commands: Commands,
-    current_dialogue: Single<Entity, With<DialogueCurrent>>,
+    current_dialogue: Single<(Entity, &Dialogue), With<DialogueCurrent>>,
     interaction_panel: Single<Entity, With<InteractionPanel>>,
 ) {
-    commands.entity(current_dialogue.into_inner()).despawn();
+    let (current_dialogue, dialogue) = current_dialogue.into_inner();
+    commands.entity(current_dialogue).despawn();
     commands.entity(interaction_panel.into_inner()).despawn();
     commands.set_state(InteractionState::None);
     commands.remove_resource::<DialogueCurrentId>();
+    commands.remove_resource::<DialogueCursor>();
+
+    // After the state change so an ending that starts an interaction isn't overridden
+    if let Some(ending) = &dialogue.ending {
+        commands.run_system_cached_with(play_interactions, Some(ending.clone()));
+    }
 }
 
 #[derive(
@@ -254,7 +404,8 @@ struct DialogueElementMarker;
 // A Sprite to be loaded for a Dialogue scene that can have its own animation
 // and be animated via DialogueActions
 struct DialogueElement {
-    path: String,
+    path:   String,
+    layout: ElementLayout,
 
     position:    Vec2,
     custom_size: Option<Vec2>,
@@ -264,12 +415,42 @@ struct DialogueElement {
     looping: bool,
 }
 
+// How the image of a DialogueElement is cut into frames
+#[derive(Debug, Reflect)]
+enum ElementLayout {
+    // The whole image as a single frame without an atlas
+    Still,
+    // Frames in a grid read left to right, then top to bottom
+    Sheet { columns: u32, rows: u32 },
+    // Separate images played in order as frames
+    Sequence(Vec<String>),
+}
+
+impl ElementLayout {
+    fn atlas_layout(&self) -> Option<TextureAtlasLayout> {
+        match *self {
+            ElementLayout::Still | ElementLayout::Sequence(_) => None,
+            ElementLayout::Sheet { columns, rows } => Some(TextureAtlasLayout::from_grid(
+                ELEMENT_TILE_SIZE,
+                columns,
+                rows,
+                None,
+                None,
+            )),
+        }
+    }
+}
+
 impl DialogueElement {
     const DEFAULT_FPS: u8 = 12;
 
     fn new(path: &'static str) -> Self {
         DialogueElement {
             path:        path.to_string(),
+            layout:      ElementLayout::Sheet {
+                columns: 1,
+                rows:    1,
+            },
             position:    Vec2::ZERO,
             custom_size: None,
             frames:      1,
@@ -278,6 +459,26 @@ impl DialogueElement {
         }
     }
 
+    fn still(path: &'static str) -> Self {
+        DialogueElement {
+            layout: ElementLayout::Still,
+            ..Self::new(path)
+        }
+    }
+
+    // Taking the first frame separately keeps sequences from being empty
+    fn sequence(first: &'static str, rest: &[&'static str]) -> Self {
+        let paths: Vec<String> = std::iter::once(first)
+            .chain(rest.iter().copied())
+            .map(str::to_string)
+            .collect();
+        DialogueElement {
+            frames: paths.len(),
+            layout: ElementLayout::Sequence(paths),
+            ..Self::new(first)
+        }
+    }
+
     fn position(mut self, position: Vec2) -> Self {
         self.position = position;
         self
@@ -293,8 +494,22 @@ impl DialogueElement {
         self
     }
 
-    fn frames(mut self, frames: usize) -> Self {
-        self.frames = frames;
+    // Frames laid out in a single row
+    fn frames(self, frames: usize) -> Self {
+        self.grid(frames as u32, 1, frames)
+    }
+
+    // The last row of the grid may be only partially filled by frames
+    fn grid(mut self, columns: u32, rows: u32, frames: usize) -> Self {
+        if let ElementLayout::Sheet {
+            columns: ref mut sheet_columns,
+            rows: ref mut sheet_rows,
+        } = self.layout
+        {
+            *sheet_columns = columns;
+            *sheet_rows = rows;
+            self.frames = frames;
+        }
         self
     }
 
@@ -308,6 +523,7 @@ impl DialogueElement {
 // Contains any actions for DialogueElements to be played during the line
 struct DialogueLine {
     speaker: Character,
+    // Locale key of the line's text
     text:    String,
     speed:   f32,
     delay:   Option<f32>,
@@ -346,14 +562,50 @@ impl DialogueLine {
 
 #[derive(Debug, Reflect)]
 enum ActionMode {
+    // Element actions
     Translate,
-    _Rotate,
+    Rotate,
     Scale,
     Activate,
     Deactivate,
+    Tint { start: Srgba, end: Srgba },
+    Flip { x: bool, y: bool },
+    // Shows a frame of the element's own animation
+    SetFrame(usize),
+
+    // Audio actions, paths are preloaded along with the elements
+    PlayMusic(String),
+    CrossfadeMusic(String),
+    StopMusic,
+    PlaySound(String),
+    StopSounds,
+    PlayVoice(String),
+
+    // Screen actions
+    // Trauma of a camera shake lasting the action's duration, from 0 to 1
+    Shake(f32),
+    FadeToBlack,
+    FadeFromBlack,
+    FadeToWhite,
+    FadeFromWhite,
+    CinematicBarsIn,
+    CinematicBarsOut,
 }
 
-// A Transform animation to be played on an indexed DialogueElement
+impl ActionMode {
+    fn audio_path(&self) -> Option<&str> {
+        match self {
+            ActionMode::PlayMusic(path)
+            | ActionMode::CrossfadeMusic(path)
+            | ActionMode::PlaySound(path)
+            | ActionMode::PlayVoice(path) => Some(path),
+            _ => None,
+        }
+    }
+}
+
+// An animation to be played on an indexed DialogueElement, or a cue for audio
+// and screen effects, which ignore the element
 #[derive(Debug, Reflect)]
 struct DialogueAction {
     element: usize,
@@ -393,6 +645,10 @@ impl DialogueAction {
         Self::new(element).mode(ActionMode::Deactivate)
     }
 
+    fn cue(mode: ActionMode) -> Self {
+        Self::new(0).mode(mode)
+    }
+
     fn mode(mut self, mode: ActionMode) -> Self {
         self.mode = mode;
         self
@@ -433,26 +689,59 @@ struct DialogueRoot;
 struct DialogueInfo {
     elements: Vec<DialogueElement>,
     lines:    Vec<DialogueLine>,
+    // Played once the dialogue has concluded, e.g. a camera_sequence Special
+    #[reflect(default)]
+    ending:   Option<EntityInteraction>,
 }
 
 #[derive(Debug, Component)]
 struct Dialogue {
     elements: Vec<Handle<Image>>,
+    audio:    Vec<Handle<AudioSource>>,
 
     // Should all have lengths equal to the number of lines
     speakers: Vec<Character>,
     texts:    Vec<TextAnimatorInfo>,
     nodes:    Vec<AnimationNodeIndex>,
+
+    ending: Option<EntityInteraction>,
 }
 
 impl Dialogue {
+    fn line_animators(
+        &self,
+        line: usize,
+        locale: &Locale,
+    ) -> (TextSimpleAnimator, TextSimpleAnimator) {
+        let TextAnimatorInfo { text, speed, delay } = &self.texts[line];
+
+        let speaker = self.speakers[line];
+        let mut prefix_animator = match speaker {
+            Character::None => TextSimpleAnimator::default(),
+            _ => {
+                TextSimpleAnimator::new(&format!("{}: ", locale.get(speaker.name_key())), f32::MAX)
+            }
+        };
+        let mut text_animator = TextSimpleAnimator::new(locale.get(text), *speed);
+
+        if let Some(seconds) = delay {
+            prefix_animator = prefix_animator.with_wait_before(*seconds);
+            text_animator = text_animator.with_wait_before(*seconds);
+        }
+
+        (prefix_animator, text_animator)
+    }
+
+    fn backlog_line(&self, line: usize, locale: &Locale) -> BacklogLine {
+        BacklogLine::new(self.speakers[line], locale.get(&self.texts[line].text))
+    }
+
     fn loaded(&self, asset_server: Res<'_, AssetServer>) -> bool {
-        self.elements.iter().all(|handle| {
-            matches!(
-                asset_server.get_load_state(handle.id()),
-                Some(LoadState::Loaded)
-            )
-        })
+        let element_ids = self.elements.iter().map(|handle| handle.id().untyped());
+        let audio_ids = self.audio.iter().map(|handle| handle.id().untyped());
+        element_ids
+            .chain(audio_ids)
+            .all(|id| matches!(asset_server.get_load_state(id), Some(LoadState::Loaded)))
     }
 }
 
@@ -465,6 +754,7 @@ pub struct DialoguePreload(Vec<DialogueId>);
 
 #[derive(Debug, Clone)]
 struct TextAnimatorInfo {
+    // Locale key, looked up when the line is played
     text:  String,
     speed: f32,
     delay: Option<f32>,
@@ -482,6 +772,15 @@ struct ElementActivated;
 #[derive(Clone, Copy, Debug, Event)]
 struct ElementDeactivated;
 
+#[derive(Clone, Copy, Debug, Event)]
+struct ElementFlipped {
+    x: bool,
+    y: bool,
+}
+
+#[derive(Clone, Copy, Debug, Event)]
+struct ElementFrameSet(usize);
+
 const ELEMENT_TILE_SIZE: UVec2 = UVec2::splat(64);
 
 fn preload_dialogues(
@@ -520,35 +819,26 @@ fn load_dialogue(
 
     let mut activation_observer = Observer::new(activate_element);
     let mut deactivation_observer = Observer::new(deactivate_element);
+    let mut flip_observer = Observer::new(flip_element);
+    let mut frame_observer = Observer::new(set_element_frame);
 
-    type ElementsInfo = (Vec<Handle<Image>>, Vec<AnimationTargetId>);
-    let (image_handles, target_ids): ElementsInfo = dialogue
+    type ElementsInfo = (Vec<Vec<Handle<Image>>>, Vec<ElementTarget>);
+    let (image_handles, targets): ElementsInfo = dialogue
         .elements
         .iter()
-        .map(|element| {
+        .enumerate()
+        .map(|(index, element)| {
             let name = Name::new(element.path.clone());
-            let target_id = AnimationTargetId::from_name(&name);
+            let target = ElementTarget {
+                id: AnimationTargetId::from_name(&name),
+                z:  Z_SPRITES + (index as f32 * 0.01),
+            };
 
             let image_handle = asset_server.load(&element.path);
-            let texture_atlas = TextureAtlas {
-                layout: asset_server.add(TextureAtlasLayout::from_grid(
-                    ELEMENT_TILE_SIZE,
-                    element.frames as u32,
-                    1,
-                    None,
-                    None,
-                )),
+            let texture_atlas = element.layout.atlas_layout().map(|layout| TextureAtlas {
+                layout: asset_server.add(layout),
                 index:  0,
-            };
-
-            let sprite_animation = {
-                let sprite_animation =
-                    SpriteAnimation::new(0, element.frames - 1, element.fps).paused();
-                match element.looping {
-                    true => sprite_animation.looping(),
-                    false => sprite_animation,
-                }
-            };
+            });
 
             let element_entity = commands
                 .spawn((
@@ -556,31 +846,63 @@ fn load_dialogue(
                     name,
                     AnimationTarget {
                         player: root_entity,
-                        id:     target_id,
+                        id:     target.id,
                     },
                     ChildOf(root_entity),
                     Sprite {
                         image: image_handle.clone_weak(),
-                        texture_atlas: Some(texture_atlas),
+                        texture_atlas: texture_atlas.clone(),
                         custom_size: element.custom_size,
                         ..Default::default()
                     },
-                    sprite_animation,
-                    Transform::default(),
+                    Transform::from_translation(element.position.extend(target.z)),
                     Visibility::Hidden,
                     RENDER_LAYER_OVERLAY,
                 ))
                 .id();
 
+            // Every frame of a sequence is waited on like the element's image
+            let mut image_handles = vec![image_handle];
+            if let ElementLayout::Sequence(paths) = &element.layout {
+                let images: Vec<Handle<Image>> =
+                    paths.iter().map(|path| asset_server.load(path)).collect();
+                image_handles.extend(images.iter().cloned());
+                commands.entity(element_entity).insert(SpriteImages(images));
+            }
+
+            // Stills have no frames to animate
+            if !matches!(element.layout, ElementLayout::Still) {
+                let sprite_animation =
+                    SpriteAnimation::new(0, element.frames - 1, element.fps).paused();
+                commands
+                    .entity(element_entity)
+                    .insert(match element.looping {
+                        true => sprite_animation.looping(),
+                        false => sprite_animation,
+                    });
+            }
+
             activation_observer.watch_entity(element_entity);
             deactivation_observer.watch_entity(element_entity);
+            flip_observer.watch_entity(element_entity);
+            frame_observer.watch_entity(element_entity);
 
-            (image_handle, target_id)
+            (image_handles, target)
         })
         .unzip();
 
     commands.spawn(activation_observer);
     commands.spawn(deactivation_observer);
+    commands.spawn(flip_observer);
+    commands.spawn(frame_observer);
+
+    let audio_handles: Vec<Handle<AudioSource>> = dialogue
+        .lines
+        .iter()
+        .flat_map(|line| line.actions.iter())
+        .filter_map(|action| action.mode.audio_path())
+        .map(|path| asset_server.load(path))
+        .collect();
 
     type AnimatorInfo = (Vec<TextAnimatorInfo>, Vec<Handle<AnimationClip>>);
     let (text_animator_info, clips): AnimatorInfo = dialogue
@@ -591,7 +913,7 @@ fn load_dialogue(
                 .actions
                 .iter()
                 .fold(AnimationClip::default(), |clip, action| {
-                    add_action_to_clip(&target_ids, clip, action)
+                    add_action_to_clip(&targets, clip, action)
                 });
 
             (
@@ -609,41 +931,47 @@ fn load_dialogue(
     commands.entity(root_entity).insert((
         AnimationGraphHandle(animation_graph_handle),
         Dialogue {
-            elements: image_handles,
+            elements: image_handles.into_iter().flatten().collect(),
+            audio: audio_handles,
             speakers,
             texts: text_animator_info,
             nodes: animation_nodes,
+            ending: dialogue.ending.clone(),
         },
     ));
 
     root_entity
 }
 
+#[derive(Debug, Clone, Copy)]
+struct ElementTarget {
+    id: AnimationTargetId,
+    z:  f32,
+}
+
 fn add_action_to_clip(
-    target_ids: &[AnimationTargetId],
+    targets: &[ElementTarget],
     mut clip: AnimationClip,
     action: &DialogueAction,
 ) -> AnimationClip {
-    let z_offset = Z_SPRITES + (action.element as f32 * 0.01);
-    let element_target_id = target_ids[action.element];
     let domain = interval(action.delay, action.delay + action.duration).unwrap();
 
     match action.mode {
         ActionMode::Translate => clip.add_curve_to_target(
-            element_target_id,
+            targets[action.element].id,
             AnimatableCurve::new(
                 animated_field!(Transform::translation),
                 EasingCurve::new(
-                    action.start.extend(z_offset),
-                    action.end.extend(z_offset),
+                    action.start.extend(targets[action.element].z),
+                    action.end.extend(targets[action.element].z),
                     action.ease,
                 )
                 .reparametrize_linear(domain)
                 .unwrap(),
             ),
         ),
-        ActionMode::_Rotate => clip.add_curve_to_target(
-            element_target_id,
+        ActionMode::Rotate => clip.add_curve_to_target(
+            targets[action.element].id,
             AnimatableCurve::new(
                 animated_field!(Transform::rotation),
                 EasingCurve::new(
@@ -656,7 +984,7 @@ fn add_action_to_clip(
             ),
         ),
         ActionMode::Scale => clip.add_curve_to_target(
-            element_target_id,
+            targets[action.element].id,
             AnimatableCurve::new(
                 animated_field!(Transform::scale),
                 EasingCurve::new(
@@ -669,38 +997,204 @@ fn add_action_to_clip(
             ),
         ),
         ActionMode::Activate => {
-            clip.add_event_to_target(element_target_id, action.delay, ElementActivated)
+            clip.add_event_to_target(targets[action.element].id, action.delay, ElementActivated)
         }
         ActionMode::Deactivate => {
-            clip.add_event_to_target(element_target_id, action.delay, ElementDeactivated)
+            clip.add_event_to_target(targets[action.element].id, action.delay, ElementDeactivated)
+        }
+        ActionMode::Tint { start, end } => clip.add_curve_to_target(
+            targets[action.element].id,
+            AnimatableCurve::new(
+                SpriteColorProperty,
+                EasingCurve::new(start, end, action.ease)
+                    .reparametrize_linear(domain)
+                    .unwrap(),
+            ),
+        ),
+        ActionMode::Flip { x, y } => clip.add_event_to_target(
+            targets[action.element].id,
+            action.delay,
+            ElementFlipped { x, y },
+        ),
+        ActionMode::SetFrame(frame) => clip.add_event_to_target(
+            targets[action.element].id,
+            action.delay,
+            ElementFrameSet(frame),
+        ),
+        ActionMode::PlayMusic(ref path) => {
+            let path = path.clone();
+            clip.add_event_fn(action.delay, move |commands, _entity, _time, _weight| {
+                commands.run_system_cached_with(play_dialogue_music, path.clone());
+            });
+        }
+        ActionMode::CrossfadeMusic(ref path) => {
+            let path = path.clone();
+            clip.add_event_fn(action.delay, move |commands, _entity, _time, _weight| {
+                commands.run_system_cached(audio_fade_out::<Music>);
+                commands.run_system_cached_with(play_dialogue_music, path.clone());
+            });
+        }
+        ActionMode::StopMusic => {
+            clip.add_event_fn(action.delay, |commands, _entity, _time, _weight| {
+                commands.run_system_cached(audio_fade_out::<Music>);
+            });
+        }
+        ActionMode::PlaySound(ref path) => {
+            let path = path.clone();
+            clip.add_event_fn(action.delay, move |commands, _entity, _time, _weight| {
+                commands.run_system_cached_with(play_dialogue_sound, path.clone());
+            });
+        }
+        ActionMode::StopSounds => {
+            clip.add_event_fn(action.delay, |commands, _entity, _time, _weight| {
+                commands.run_system_cached(audio_fade_out::<Sound>);
+            });
+        }
+        ActionMode::PlayVoice(ref path) => {
+            let path = path.clone();
+            clip.add_event_fn(action.delay, move |commands, _entity, _time, _weight| {
+                commands.run_system_cached_with(play_dialogue_voice, path.clone());
+            });
+        }
+        ActionMode::Shake(trauma) => {
+            let duration = action.duration;
+            // Shakes the world cameras like any other impulse, following the camera motion setting
+            clip.add_event_fn(action.delay, move |commands, _entity, _time, _weight| {
+                commands.trigger(CameraImpulse::Rumble(trauma, duration));
+            });
+        }
+        ActionMode::FadeToBlack => {
+            clip.add_event_fn(action.delay, |commands, _entity, _time, _weight| {
+                commands.run_system_cached(fade_to_black);
+            });
+        }
+        ActionMode::FadeFromBlack => {
+            clip.add_event_fn(action.delay, |commands, _entity, _time, _weight| {
+                commands.run_system_cached(fade_from_black);
+            });
+        }
+        ActionMode::FadeToWhite => {
+            clip.add_event_fn(action.delay, |commands, _entity, _time, _weight| {
+                commands.run_system_cached(fade_to_white);
+            });
+        }
+        ActionMode::FadeFromWhite => {
+            clip.add_event_fn(action.delay, |commands, _entity, _time, _weight| {
+                commands.run_system_cached(fade_from_white);
+            });
+        }
+        ActionMode::CinematicBarsIn => {
+            clip.add_event_fn(action.delay, |commands, _entity, _time, _weight| {
+                commands.run_system_cached(cinematic_bars_in);
+            });
+        }
+        ActionMode::CinematicBarsOut => {
+            clip.add_event_fn(action.delay, |commands, _entity, _time, _weight| {
+                commands.run_system_cached(cinematic_bars_out);
+            });
         }
     };
 
     clip
 }
 
+fn play_dialogue_music(
+    In(path): In<String>,
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+) {
+    commands.spawn((
+        Music,
+        Name::new("Dialogue Music"),
+        Unducked,
+        AudioPlayer::new(asset_server.load::<AudioSource>(path)),
+        PlaybackSettings::LOOP.with_volume(Volume::SILENT),
+        AudioFadeIn,
+    ));
+}
+
+fn play_dialogue_sound(
+    In(path): In<String>,
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    settings: Res<Persistent<Settings>>,
+) {
+    commands.spawn((
+        Sound,
+        Name::new("Dialogue Sound"),
+        AudioPlayer::new(asset_server.load::<AudioSource>(path)),
+        PlaybackSettings::DESPAWN.with_volume(Sound::volume(&settings)),
+    ));
+}
+
+fn play_dialogue_voice(
+    In(path): In<String>,
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    settings: Res<Persistent<Settings>>,
+) {
+    commands.spawn((
+        Voice,
+        Name::new("Dialogue Voice"),
+        AudioPlayer::new(asset_server.load::<AudioSource>(path)),
+        PlaybackSettings::DESPAWN.with_volume(Voice::volume(&settings)),
+    ));
+}
+
+fn set_element_frame(
+    trigger: Trigger<ElementFrameSet>,
+    mut q_animations: Query<&mut SpriteAnimation, With<DialogueElementMarker>>,
+) {
+    match q_animations.get_mut(trigger.target()) {
+        Ok(mut animation) => animation.go_to_frame(trigger.0),
+        Err(_) => warn!("Dialogue element {} has no frames to set", trigger.target()),
+    }
+}
+
+// Music other than the dialogue's own is lowered while a dialogue plays
+fn duck_music<const DUCKED: bool>(mut duck: ResMut<MusicDuck>) {
+    duck.active = DUCKED;
+}
+
+type ElementVisibility<'a> = (Option<&'a mut SpriteAnimation>, &'a mut Visibility);
+
 fn activate_element(
     trigger: Trigger<ElementActivated>,
-    mut q_elements: Query<(&mut SpriteAnimation, &mut Visibility), With<DialogueElementMarker>>,
+    mut q_elements: Query<ElementVisibility, With<DialogueElementMarker>>,
 ) {
-    let (mut animation, mut visibility) = q_elements
+    let (animation, mut visibility) = q_elements
         .get_mut(trigger.target())
-        .expect("All Dialogue elements should have Sprite Animation and Visibility");
-    animation.as_mut().play();
+        .expect("All Dialogue elements should have Visibility");
+    if let Some(mut animation) = animation {
+        animation.play();
+    }
     *visibility = Visibility::Visible;
 }
 
 fn deactivate_element(
     trigger: Trigger<ElementDeactivated>,
-    mut q_elements: Query<(&mut SpriteAnimation, &mut Visibility), With<DialogueElementMarker>>,
+    mut q_elements: Query<ElementVisibility, With<DialogueElementMarker>>,
 ) {
-    let (mut animation, mut visibility) = q_elements
+    let (animation, mut visibility) = q_elements
         .get_mut(trigger.target())
-        .expect("All Dialogue elements should have Sprite Animation and Visibility");
-    animation.as_mut().pause();
+        .expect("All Dialogue elements should have Visibility");
+    if let Some(mut animation) = animation {
+        animation.pause();
+    }
     *visibility = Visibility::Hidden;
 }
 
+fn flip_element(
+    trigger: Trigger<ElementFlipped>,
+    mut q_elements: Query<&mut Sprite, With<DialogueElementMarker>>,
+) {
+    let mut sprite = q_elements
+        .get_mut(trigger.target())
+        .expect("All Dialogue elements should have a Sprite");
+    sprite.flip_x = trigger.x;
+    sprite.flip_y = trigger.y;
+}
+
 #[derive(Debug, Default, Deref, DerefMut, Resource, Reflect)]
 #[reflect(Resource)]
 struct DialogueStorage(EnumMap<DialogueId, DialogueInfo>);
@@ -768,48 +1262,50 @@ fn add_new_dialogue(mut dialogue_storage: ResMut<DialogueStorage>) {
                     .custom_size(Vec2::splat(SCENE_AREA_HEIGHT))
                     .frames(4)
                     .fps(8),
-                DialogueElement::new("sprites/ucko/group.png").position(OFFSCREEN_RIGHT),
-                DialogueElement::new("sprites/bucko/bones_1.png"),
-                DialogueElement::new("sprites/bucko/bones_2.png"),
+                DialogueElement::still("sprites/ucko/group.png").position(OFFSCREEN_RIGHT),
+                DialogueElement::sequence(
+                    "sprites/bucko/bones_1.png",
+                    &["sprites/bucko/bones_2.png"],
+                )
+                .fps(4)
+                .looping(),
+                // 8x8 sheet of 64x64 frames, only the first is shown
                 DialogueElement::new("sprites/bucko/escape.png"),
             ],
             lines:    vec![
-                DialogueLine::new(
-                    Character::Bucko,
-                    "Pardon me. Did I miss something? What's going on?",
-                )
-                .delay(7.5)
-                .add_action(DialogueAction::activate(0))
-                .add_action(
-                    DialogueAction::new(0)
-                        .mode(ActionMode::Scale)
-                        .start(Vec2::splat(0.1))
-                        .end(Vec2::splat(1.0))
-                        .ease(EaseFunction::Steps(3, JumpAt::End))
-                        .duration(6.0),
-                )
-                .add_action(DialogueAction::deactivate(0).delay(6.5))
-                .add_action(DialogueAction::activate(1).delay(6.5))
-                .add_action(DialogueAction::deactivate(1).delay(8.0))
-                .add_action(DialogueAction::activate(2).delay(8.0))
-                .add_action(
-                    DialogueAction::new(2)
-                        .start(Vec2::ZERO)
-                        .end(Vec2::NEG_Y * 128.0)
-                        .ease(EaseFunction::ExponentialOut)
-                        .delay(8.0)
-                        .duration(2.0),
-                )
-                .add_action(
-                    DialogueAction::new(2)
-                        .mode(ActionMode::Scale)
-                        .start(Vec2::splat(1.0))
-                        .end(Vec2::splat(1.5))
-                        .ease(EaseFunction::ExponentialOut)
-                        .delay(8.0)
-                        .duration(2.0),
-                ),
-                DialogueLine::new(Character::Unknown, "...")
+                DialogueLine::new(Character::Bucko, "dialogue.ucko_intro.0")
+                    .delay(7.5)
+                    .add_action(DialogueAction::activate(0))
+                    .add_action(
+                        DialogueAction::new(0)
+                            .mode(ActionMode::Scale)
+                            .start(Vec2::splat(0.1))
+                            .end(Vec2::splat(1.0))
+                            .ease(EaseFunction::Steps(3, JumpAt::End))
+                            .duration(6.0),
+                    )
+                    .add_action(DialogueAction::deactivate(0).delay(6.5))
+                    .add_action(DialogueAction::activate(1).delay(6.5))
+                    .add_action(DialogueAction::deactivate(1).delay(8.0))
+                    .add_action(DialogueAction::activate(2).delay(8.0))
+                    .add_action(
+                        DialogueAction::new(2)
+                            .start(Vec2::ZERO)
+                            .end(Vec2::NEG_Y * 128.0)
+                            .ease(EaseFunction::ExponentialOut)
+                            .delay(8.0)
+                            .duration(2.0),
+                    )
+                    .add_action(
+                        DialogueAction::new(2)
+                            .mode(ActionMode::Scale)
+                            .start(Vec2::splat(1.0))
+                            .end(Vec2::splat(1.5))
+                            .ease(EaseFunction::ExponentialOut)
+                            .delay(8.0)
+                            .duration(2.0),
+                    ),
+                DialogueLine::new(Character::Unknown, "dialogue.ucko_intro.1")
                     .speed(1.0)
                     .delay(2.0)
                     .add_action(DialogueAction::activate(3))
@@ -827,10 +1323,13 @@ fn add_new_dialogue(mut dialogue_storage: ResMut<DialogueStorage>) {
                             .ease(EaseFunction::SmoothStep)
                             .duration(2.0),
                     ),
-                DialogueLine::new(Character::Bucko, "I see... a pleasure talking to ya-"),
-                DialogueLine::new(Character::Bucko, "Uh oh..."),
-                DialogueLine::new(Character::Bucko, "AAAAAAIIIIEEEEEE!!"),
+                DialogueLine::new(Character::Bucko, "dialogue.ucko_intro.2"),
+                DialogueLine::new(Character::Bucko, "dialogue.ucko_intro.3")
+                    .add_action(DialogueAction::activate(4)),
+                DialogueLine::new(Character::Bucko, "dialogue.ucko_intro.4")
+                    .add_action(DialogueAction::cue(ActionMode::Shake(0.8)).duration(1.5)),
             ],
+            ending:   None,
         },
     );
 
@@ -844,6 +1343,7 @@ fn add_new_dialogue(mut dialogue_storage: ResMut<DialogueStorage>) {
                     .looping(),
             ],
             lines:    vec![],
+            ending:   None,
         },
     );
 }
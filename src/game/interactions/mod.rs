@@ -1,14 +1,22 @@
-use std::sync::Arc;
-
 use bevy::prelude::*;
 use serde::{Deserialize, Serialize};
 
 use super::*;
+use crate::{
+    locale::{Locale, LocalizedText},
+    progress::TimeOfDay,
+};
+use backlog::*;
 use dialogue::*;
 use monologue::*;
+use prompt::*;
+use special::*;
 
+pub mod backlog;
 pub mod dialogue;
 pub mod monologue;
+pub mod prompt;
+pub mod special;
 
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, SubStates)]
 #[source(InGame = InGame)]
@@ -20,15 +28,18 @@ pub enum InteractionState {
 }
 
 pub fn interactions_plugin(app: &mut App) {
-    app.add_plugins(dialogue_plugin)
+    app.add_plugins((backlog_plugin, dialogue_plugin, prompt_plugin))
         .add_systems(OnExit(InteractionState::None), disable_movement)
         .add_systems(OnExit(InteractionState::Text), enable_movement)
         .add_systems(OnExit(InteractionState::Dialogue), enable_movement)
         .add_systems(
             Update,
             (
-                advance_interaction_text
-                    .run_if(pressed_advance_key.and(any_with_component::<InteractionPanel>)),
+                advance_interaction_text.run_if(
+                    pressed_advance_key
+                        .and(any_with_component::<InteractionPanel>)
+                        .and(not(any_with_component::<BacklogPanel>)),
+                ),
                 conclude_text_interaction
                     .run_if(in_state(InteractionState::Text).and(on_event::<InteractionAdvance>)),
             ),
@@ -36,8 +47,12 @@ pub fn interactions_plugin(app: &mut App) {
         .add_event::<InteractionAdvance>()
         .init_state::<InteractionState>()
         .init_resource::<MonologueServer>()
+        .init_resource::<SpecialInteractions>()
         .register_type::<EntityInteraction>()
+        .register_type::<FollowUpInteraction>()
+        .register_type::<InteractionId>()
         .register_type::<InteractTarget>()
+        .register_type::<TimedInteraction>()
         .register_type::<Monologue>()
         .register_type::<MonologueId>()
         .register_type::<MonologueServer>();
@@ -68,33 +83,52 @@ impl InteractTarget {
 #[derive(Debug, Clone, Component, Reflect, Serialize, Deserialize)]
 #[reflect(Component, Serialize, Deserialize)]
 pub enum EntityInteraction {
+    // Locale key of the text to show
     Text(String),
     Monologue(MonologueId),
     Dialogue(DialogueId),
-    Special(Entity),
+    // Name of a handler in SpecialInteractions followed by its arguments
+    Special(String),
 }
 
-#[derive(Component)]
-pub struct SpecialInteraction(SpecialInteractionFn);
-type SpecialInteractionFn = Arc<dyn Fn(&mut Commands, Entity) + Send + Sync>;
+// Identifies an interaction placed in a Tiled map across reloads and save slots
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Component, Reflect, Serialize, Deserialize)]
+#[reflect(Component, Serialize, Deserialize)]
+pub struct InteractionId {
+    pub map:    topdown::maps::MapKey,
+    // Object id assigned by Tiled, stable as long as the object isn't recreated
+    pub object: u32,
+}
 
-impl core::fmt::Debug for SpecialInteraction {
-    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        f.debug_tuple("SpecialInteraction").finish()
-    }
+// Played in place of the EntityInteraction once it has been consumed,
+// makes the EntityInteraction one-shot like Dialogues are
+#[derive(Debug, Clone, Component, Reflect)]
+#[reflect(Component)]
+pub struct FollowUpInteraction(pub EntityInteraction);
+
+// Played in place of the EntityInteraction at a time of day, e.g. a different
+// monologue at night, and never consumed
+#[derive(Debug, Clone, Component, Reflect)]
+#[reflect(Component)]
+pub struct TimedInteraction {
+    pub time:        TimeOfDay,
+    pub interaction: EntityInteraction,
 }
 
-impl SpecialInteraction {
-    pub fn new(func: impl Fn(&mut Commands, Entity) + Send + Sync + 'static) -> Self {
-        SpecialInteraction(Arc::new(func))
+impl EntityInteraction {
+    // Dialogues only play once, anything with a follow-up does as well
+    pub fn one_shot(&self, follow_up: Option<&FollowUpInteraction>) -> bool {
+        follow_up.is_some() || matches!(self, EntityInteraction::Dialogue(_))
     }
 }
 
 pub fn play_interactions(
     In(input): In<Option<EntityInteraction>>,
-    special_interactions: Query<&SpecialInteraction>,
+    special_interactions: Res<SpecialInteractions>,
     mut monologue_server: ResMut<MonologueServer>,
+    mut e_writer: EventWriter<BacklogLine>,
     mut commands: Commands,
+    locale: Res<Locale>,
 ) {
     const CLEAR: f32 = 0.0;
     const OPACITY_75: f32 = 0.75;
@@ -105,18 +139,21 @@ pub fn play_interactions(
     };
 
     match interaction {
-        EntityInteraction::Text(text) => {
+        EntityInteraction::Text(key) => {
+            let text = locale.get(&key);
             commands.set_state(InteractionState::Text);
             commands
                 .spawn(interaction_panel(OPACITY_75))
-                .with_child(interaction_text(&text));
+                .with_child(interaction_text(text, &locale));
+            e_writer.write(BacklogLine::new(Character::None, text));
         }
         EntityInteraction::Monologue(id) => {
-            let text = monologue_server.next_line(&id);
+            let text = monologue_server.next_line(&id, &locale);
             commands.set_state(InteractionState::Text);
             commands
                 .spawn(interaction_panel(OPACITY_75))
-                .with_child(interaction_text(text));
+                .with_child(interaction_text(&text, &locale));
+            e_writer.write(BacklogLine::new(Character::None, text));
         }
         EntityInteraction::Dialogue(id) => {
             if id == DialogueId::None {
@@ -127,15 +164,17 @@ pub fn play_interactions(
             commands.insert_resource(DialogueCurrentId(id));
             commands
                 .spawn(interaction_panel(CLEAR))
-                .with_child(interaction_prefix())
-                .with_child(interaction_text(""));
+                .with_child(interaction_prefix(&locale))
+                .with_child(interaction_text("", &locale))
+                .with_child(dialogue_mode_indicator());
         }
-        EntityInteraction::Special(entity) => {
-            let Ok(SpecialInteraction(func)) = special_interactions.get(entity) else {
-                warn!("Entity {} has no SpecialInteraction", entity);
+        EntityInteraction::Special(call) => {
+            let (name, args) = parse_special(&call);
+            let Some(system_id) = special_interactions.get(name) else {
+                warn!("No special interaction named \"{name}\"");
                 return;
             };
-            func(&mut commands, entity);
+            commands.run_system_with(system_id, args);
         }
     }
 }
@@ -189,11 +228,12 @@ fn interaction_panel(opacity: f32) -> impl Bundle {
     )
 }
 
-fn interaction_prefix() -> impl Bundle {
+fn interaction_prefix(locale: &Locale) -> impl Bundle {
     (
         InteractionPrefix,
         Text::new(""),
         TextFont {
+            font: locale.text_font.clone_weak(),
             font_size: 16.0,
             ..default()
         },
@@ -202,11 +242,12 @@ fn interaction_prefix() -> impl Bundle {
     )
 }
 
-fn interaction_text(text: &str) -> impl Bundle {
+fn interaction_text(text: &str, locale: &Locale) -> impl Bundle {
     (
         InteractionText,
         Text::new(""),
         TextFont {
+            font: locale.text_font.clone_weak(),
             font_size: 16.0,
             ..default()
         },
@@ -216,7 +257,8 @@ fn interaction_text(text: &str) -> impl Bundle {
 }
 
 #[allow(dead_code)]
-#[derive(Debug, Clone, Copy, Default, Reflect)]
+#[derive(Debug, Clone, Copy, Default, Reflect, Serialize, Deserialize)]
+#[reflect(Default, Serialize, Deserialize)]
 pub enum Character {
     #[default]
     None,
@@ -230,12 +272,19 @@ pub enum Character {
     Cowbucko,
 }
 
-impl std::fmt::Display for Character {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl Character {
+    // Locale key of the name shown before the Character's lines
+    pub fn name_key(&self) -> &'static str {
         match self {
-            Character::Unknown => write!(f, "???"),
-            Character::Brock => write!(f, "Bane \"The Brock\" Bronson"),
-            _ => write!(f, "{:?}", self),
+            Character::None => "character.none",
+            Character::Unknown => "character.unknown",
+            Character::Bucko => "character.bucko",
+            Character::Ninjucko => "character.ninjucko",
+            Character::Wizucko => "character.wizucko",
+            Character::Bartucko => "character.bartucko",
+            Character::Brock => "character.brock",
+            Character::Maducko => "character.maducko",
+            Character::Cowbucko => "character.cowbucko",
         }
     }
 }
@@ -4,7 +4,7 @@ use bevy::{platform::collections::HashMap, prelude::*};
 use nohash_hasher::IsEnabled;
 use serde::{Deserialize, Serialize};
 
-use crate::{BuildBuckoNoHashHasher, EnumMap};
+use crate::{BuildBuckoNoHashHasher, EnumMap, locale::Locale};
 
 #[derive(
     Debug, Default, Clone, Copy, Component, PartialEq, Eq, Reflect, Serialize, Deserialize,
@@ -24,23 +24,19 @@ impl Hash for MonologueId {
 
 impl IsEnabled for MonologueId {}
 
+// Lines of a monologue are the lines of its localized text
 #[derive(Debug, Reflect, Serialize, Deserialize)]
 pub struct Monologue {
     loop_index: usize,
-    text:       String,
+    key:        String,
 }
 
 impl Monologue {
-    fn new(loop_index: usize, text: String) -> Self {
-        Monologue { loop_index, text }
-    }
-
-    fn get(&self, line: usize) -> Option<&str> {
-        self.text.lines().nth(line)
-    }
-
-    fn len(&self) -> usize {
-        self.text.lines().count()
+    fn new(loop_index: usize, key: &str) -> Self {
+        Monologue {
+            loop_index,
+            key: key.to_string(),
+        }
     }
 }
 
@@ -52,7 +48,7 @@ pub struct MonologueServer {
 }
 
 impl MonologueServer {
-    pub fn next_line(&mut self, id: &MonologueId) -> &str {
+    pub fn next_line(&mut self, id: &MonologueId, locale: &Locale) -> String {
         let line_index = self.progress.entry(*id).or_default();
 
         let monologue = self
@@ -60,13 +56,23 @@ impl MonologueServer {
             .get(id)
             .expect("All MonologueId should be in storage");
 
-        let line = monologue
-            .get(*line_index)
-            .expect("line index should never exceed line count");
+        let text = locale.get(&monologue.key);
+        let line_count = text.lines().count();
+
+        // Languages can differ in line count, so the index may be past the end
+        if *line_index >= line_count {
+            *line_index = monologue.loop_index.min(line_count.saturating_sub(1));
+        }
+
+        let line = text
+            .lines()
+            .nth(*line_index)
+            .unwrap_or_default()
+            .to_string();
 
         *line_index += 1;
 
-        if *line_index == monologue.len() {
+        if *line_index >= line_count {
             *line_index = monologue.loop_index
         }
 
@@ -82,18 +88,8 @@ impl FromWorld for MonologueServer {
         let progress: EnumMap<MonologueId, usize> =
             HashMap::with_hasher(BuildBuckoNoHashHasher::default());
 
-        storage.insert(MonologueId::None, Monologue::new(0, String::new()));
-
-        storage.insert(
-            MonologueId::Test,
-            Monologue::new(2, {
-                let mut text = "First line\n".to_string();
-                text.push_str("Second line\n");
-                text.push_str("Third Line\n");
-                text.push_str("Last line, go back to third");
-                text
-            }),
-        );
+        storage.insert(MonologueId::None, Monologue::new(0, "monologue.none"));
+        storage.insert(MonologueId::Test, Monologue::new(2, "monologue.test"));
 
         MonologueServer { storage, progress }
     }
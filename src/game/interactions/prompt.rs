@@ -0,0 +1,66 @@
+use bevy::prelude::*;
+
+use super::*;
+use crate::StandardFont;
+
+pub fn prompt_plugin(app: &mut App) {
+    app.add_systems(
+        OnExit(InteractionState::None),
+        set_prompt_visibility::<false>,
+    )
+    .add_systems(
+        OnEnter(InteractionState::None),
+        set_prompt_visibility::<true>,
+    );
+}
+
+// Floating label showing the interact key for the current InteractTarget
+#[derive(Debug, Component)]
+pub struct InteractPrompt;
+
+// Sprite color of a highlighted InteractTarget before it was highlighted
+#[derive(Debug, Component)]
+pub struct Highlighted(pub Color);
+
+pub const HIGHLIGHT_COLOR: Color = Color::srgb(1.0, 0.95, 0.6);
+
+pub fn interact_prompt(
+    settings: &Settings,
+    font: &StandardFont,
+    font_size: f32,
+    translation: Vec3,
+) -> impl Bundle {
+    (
+        InteractPrompt,
+        Name::new("Interact Prompt"),
+        Text2d::new(format!("[{}]", key_label(settings.interact))),
+        TextFont {
+            font: font.clone_weak(),
+            font_size,
+            font_smoothing: bevy::text::FontSmoothing::None,
+            ..default()
+        },
+        TextColor(WHITE.into()),
+        Transform::from_translation(translation),
+    )
+}
+
+// Name of a key as printed on the keyboard, e.g. KeyE -> E
+pub fn key_label(key: KeyCode) -> String {
+    let name = format!("{key:?}");
+    ["Key", "Digit"]
+        .into_iter()
+        .find_map(|prefix| name.strip_prefix(prefix).map(str::to_string))
+        .unwrap_or(name)
+}
+
+fn set_prompt_visibility<const VISIBLE: bool>(
+    mut q_prompts: Query<&mut Visibility, With<InteractPrompt>>,
+) {
+    q_prompts.iter_mut().for_each(|mut visibility| {
+        *visibility = match VISIBLE {
+            true => Visibility::Inherited,
+            false => Visibility::Hidden,
+        };
+    });
+}
@@ -0,0 +1,97 @@
+use bevy::{ecs::system::SystemId, platform::collections::HashMap, prelude::*};
+use bevy_ecs_tiled::prelude::*;
+
+use super::*;
+
+// Arguments following the name of an EntityInteraction::Special,
+// e.g. "open_gate north 2" gives ["north", "2"]
+pub type SpecialArgs = Vec<String>;
+
+// Handlers of EntityInteraction::Special, looked up by name
+#[derive(Debug, Default, Resource)]
+pub struct SpecialInteractions(HashMap<String, SystemId<In<SpecialArgs>>>);
+
+impl SpecialInteractions {
+    pub fn get(&self, name: &str) -> Option<SystemId<In<SpecialArgs>>> {
+        self.0.get(name).copied()
+    }
+
+    pub fn contains(&self, name: &str) -> bool {
+        self.0.contains_key(name)
+    }
+}
+
+pub trait SpecialInteractionsExt {
+    fn add_special_interaction<M>(
+        &mut self,
+        name: &str,
+        system: impl IntoSystem<In<SpecialArgs>, (), M> + 'static,
+    ) -> &mut Self;
+}
+
+impl SpecialInteractionsExt for App {
+    fn add_special_interaction<M>(
+        &mut self,
+        name: &str,
+        system: impl IntoSystem<In<SpecialArgs>, (), M> + 'static,
+    ) -> &mut Self {
+        let system_id = self.world_mut().register_system(system);
+        let mut special_interactions = self
+            .world_mut()
+            .get_resource_or_init::<SpecialInteractions>();
+
+        if special_interactions
+            .0
+            .insert(name.to_string(), system_id)
+            .is_some()
+        {
+            warn!("Special interaction \"{name}\" registered more than once");
+        }
+        self
+    }
+}
+
+// Splits "name arg1 arg2" into the handler name and its arguments
+pub fn parse_special(call: &str) -> (&str, SpecialArgs) {
+    let mut words = call.split_whitespace();
+    let name = words.next().unwrap_or_default();
+    (name, words.map(str::to_string).collect())
+}
+
+// Reports Special interactions of a freshly loaded Tiled map that have no handler
+pub fn validate_special_interactions(
+    trigger: Trigger<TiledMapCreated>,
+    q_tiled_maps: Query<&TiledMapStorage, With<TiledMapMarker>>,
+    q_interactables: Query<(
+        Option<&Name>,
+        Option<&EntityInteraction>,
+        Option<&FollowUpInteraction>,
+    )>,
+    special_interactions: Res<SpecialInteractions>,
+) {
+    let Ok(map_storage) = q_tiled_maps.get(trigger.entity) else {
+        return;
+    };
+
+    map_storage.objects.iter().for_each(|(tiled_id, entity)| {
+        let Ok((name, interaction, follow_up)) = q_interactables.get(*entity) else {
+            return;
+        };
+
+        let follow_up = follow_up.map(|FollowUpInteraction(follow_up)| follow_up);
+        interaction
+            .into_iter()
+            .chain(follow_up)
+            .filter_map(|interaction| match interaction {
+                EntityInteraction::Special(call) => Some(parse_special(call).0),
+                _ => None,
+            })
+            .filter(|special_name| !special_interactions.contains(special_name))
+            .for_each(|special_name| {
+                error!(
+                    "Object {tiled_id} ({}) uses unknown special interaction \"{special_name}\"",
+                    name.map(Name::as_str).unwrap_or("unnamed")
+                );
+            });
+    });
+}
@@ -1,7 +1,8 @@
 #![allow(clippy::type_complexity)]
 use avian2d::prelude::*;
 use bevy::{
-    animation::{AnimationTarget, AnimationTargetId, animated_field},
+    animation::{AnimationTarget, AnimationTargetId},
+    platform::collections::HashMap,
     prelude::*,
     time::Stopwatch,
 };
@@ -10,16 +11,31 @@ use bevy_ecs_tilemap::tiles::TileStorage;
 
 use super::*;
 use crate::{
-    RENDER_LAYER_WORLD, WINDOW_HEIGHT, WINDOW_WIDTH,
+    RENDER_LAYER_WORLD, StandardFont, WINDOW_HEIGHT, WINDOW_WIDTH,
     animation::*,
     audio::*,
     despawn_screen,
     game::{
         effects::*,
-        interactions::{dialogue::DialoguePreload, *},
+        interactions::{dialogue::DialoguePreload, prompt::*, special::*, *},
     },
     progress::*,
 };
+use camera_sequence::*;
+use camera_zones::*;
+use day_night::*;
+use map_audio::*;
+use maps::*;
+use surface::*;
+use triggers::*;
+
+mod camera_sequence;
+mod camera_zones;
+mod day_night;
+mod map_audio;
+pub mod maps;
+mod surface;
+mod triggers;
 
 #[derive(Debug, Component)]
 struct OnTopDown;
@@ -35,73 +51,86 @@ enum TopDownState {
 }
 
 pub fn topdown_plugin(app: &mut App) {
+    app.add_plugins((
+        topdown_camera_sequence_plugin,
+        topdown_camera_zones_plugin,
+        topdown_day_night_plugin,
+        topdown_map_audio_plugin,
+        topdown_maps_plugin,
+        topdown_surface_plugin,
+        topdown_triggers_plugin,
+    ));
+
     app.add_systems(
         OnEnter(GameState::TopDown),
-        (
-            setup_camera,
-            setup_player,
-            setup_first_launch.run_if(not(has_progress_flag(ProgressFlag::FirstLaunch))),
-        )
-            .chain(),
+        (setup_camera, setup_player).chain(),
     )
     .add_systems(
         OnExit(GameState::TopDown),
         (despawn_screen::<OnTopDown>, reset_map_info),
     );
 
-    app.add_systems(OnEnter(TopDownState::Loading), setup_map)
-        .add_systems(
-            Update,
-            wait_for_ready.run_if(in_state(TopDownState::Loading)),
-        );
+    app.add_systems(
+        OnEnter(TopDownState::Loading),
+        (
+            setup_map,
+            load_first_launch.run_if(not(has_progress_flag(ProgressFlag::FirstLaunch))),
+        ),
+    )
+    .add_systems(
+        Update,
+        wait_for_ready.run_if(in_state(TopDownState::Loading)),
+    );
 
-    app.add_systems(OnEnter(TopDownState::FirstLaunch), fade_from_white)
-        .add_systems(
-            Update,
-            wait_first_launch.run_if(in_state(TopDownState::FirstLaunch)),
-        );
+    app.add_systems(
+        OnEnter(TopDownState::FirstLaunch),
+        (fade_from_white, setup_first_launch),
+    )
+    .add_systems(
+        Update,
+        wait_first_launch.run_if(in_state(TopDownState::FirstLaunch)),
+    );
 
     app.add_systems(
         OnEnter(TopDownState::Ready),
-        (
-            fade_from_whatever,
-            save_progress_to_disk,
-            audio_fade_out::<Music>,
-            enable_movement,
-        ),
+        (fade_from_whatever, save_progress_to_disk, enable_movement),
     )
     .add_systems(
         Update,
         (
             camera_system,
-            update_near_interactables,
-            update_player_submerged,
+            (update_near_interactables, update_interact_prompt).chain(),
+            update_player_surface,
             update_player_z,
-            player_hop.run_if(not(player_submerged)),
             player_swim.run_if(player_submerged),
             get_topdown_interactions
                 .pipe(play_interactions)
                 .run_if(in_state(InteractionState::None).and(just_pressed_interact)),
         )
             .run_if(in_state(TopDownState::Ready).and(in_state(MovementEnabled))),
+    )
+    .add_systems(
+        Update,
+        update_player_animator.run_if(in_state(TopDownState::Ready)),
     );
 
-    app.add_systems(OnEnter(TopDownState::Warping), fade_to_black)
-        .add_systems(
-            Update,
-            warp_player.run_if(in_state(TopDownState::Warping).and(on_event::<FadeIn>)),
-        );
+    app.add_systems(OnEnter(TopDownState::Warping), iris_to_warp);
 
     app.add_observer(initialize_map_info)
+        .add_observer(place_player_at_spawn)
+        .add_observer(validate_special_interactions)
         .add_sub_state::<TopDownState>()
         .init_resource::<MapInfo>()
-        .init_resource::<TopdownMapHandles>()
-        .register_type::<HopState>()
+        .init_resource::<SpawnPoints>()
         .register_type::<Submerged>()
         .register_type::<WaterTile>()
+        .register_type::<SpawnPoint>()
         .register_type::<Warp>();
 }
 
+// Scale of the camera until a map sets its own
+const CAMERA_SCALE: f32 = 0.5;
+
 fn setup_camera(mut commands: Commands, progress: Res<Progress>) {
     use crate::auto_scaling::AspectRatio;
     use bevy::render::camera::ScalingMode;
@@ -118,7 +147,8 @@ fn setup_camera(mut commands: Commands, progress: Res<Progress>) {
             clear_color: ClearColorConfig::Custom(Color::BLACK),
             ..default()
         },
-        Transform::from_translation(progress.position.extend(0.0)).with_scale(Vec3::splat(0.5)),
+        Transform::from_translation(progress.position.extend(0.0))
+            .with_scale(Vec3::splat(CAMERA_SCALE)),
         AspectRatio(16.0 / 9.0),
         Projection::from({
             OrthographicProjection {
@@ -142,10 +172,9 @@ fn setup_player(
 ) {
     info!("Spawning TopDown Player");
 
-    let player_sprites = asset_server.load("sprites/bucko_bounce.png");
-    asset_tracker.push(player_sprites.clone_weak().untyped());
-    let layout = TextureAtlasLayout::from_grid(UVec2::splat(32), 8, 3, None, None);
-    let layout = asset_server.add(layout);
+    let (animations, sprite) =
+        SpriteAnimations::load(&asset_server, "sprites/bucko_bounce.anim.ron");
+    asset_tracker.push(animations.clone_weak().untyped());
 
     commands
         .spawn((
@@ -156,9 +185,11 @@ fn setup_player(
             Transform::from_translation(progress.position.extend(0.0)),
             Visibility::default(),
             //
-            Sprite::from_atlas_image(player_sprites, TextureAtlas { layout, index: 0 }),
-            HopState::Idle,
+            sprite,
+            animations,
+            player_animator(),
             Submerged::default(),
+            PlayerSurface::default(),
             SpriteAnimation::set_frame(0),
             //
             RigidBody::Dynamic,
@@ -169,45 +200,58 @@ fn setup_player(
             //
             RENDER_LAYER_WORLD,
         ))
-        .observe(update_player_hop)
-        .observe(update_player_animations);
+        .observe(player_hop)
+        .observe(player_frame_events);
 }
 
 #[derive(Debug, Component)]
 struct FirstLaunch;
 
-fn setup_first_launch(
+// Loaded with the map so the pan and the title's fade start together
+#[derive(Debug, Resource)]
+struct FirstLaunchAssets {
+    // Pans up to the title and back down in step with its fade
+    sequence: Handle<CameraSequence>,
+    title:    Handle<Image>,
+}
+
+fn load_first_launch(
     mut commands: Commands,
     mut asset_tracker: ResMut<AssetTracker>,
     asset_server: Res<AssetServer>,
-    camera: Single<(Entity, &Name, &Transform), With<WorldCamera>>,
+) {
+    let sequence: Handle<CameraSequence> = asset_server.load("sequences/first_launch.camera.ron");
+    let title: Handle<Image> = asset_server.load("title.png");
+    asset_tracker.push(sequence.clone_weak().untyped());
+    asset_tracker.push(title.clone_weak().untyped());
+
+    commands.insert_resource(FirstLaunchAssets { sequence, title });
+}
+
+fn setup_first_launch(
+    mut commands: Commands,
+    assets: Res<FirstLaunchAssets>,
+    asset_server: Res<AssetServer>,
+    camera_transform: Single<&Transform, With<WorldCamera>>,
 ) {
     info!("Spawning First Launch elements");
 
-    let (camera, camera_name, camera_transform) = camera.into_inner();
+    if let Some(path) = assets.sequence.path() {
+        commands.trigger(PlayCameraSequence(path.to_string()));
+    }
     let camera_out = camera_transform.translation;
-    let camera_in = camera_out + Vec3::Y * 128.0;
-    let camera_id = AnimationTargetId::from_name(camera_name);
 
     let title_name = Name::new("Title");
     let title_id = AnimationTargetId::from_name(&title_name);
 
     let (graph, node) = AnimationGraph::from_clip(asset_server.add({
         let key_frames = [0.0, 4.0, 8.0, 16.0, 20.0];
-
-        let positions = [camera_out, camera_out, camera_in, camera_in, camera_out];
         let opacities = [0.0, 0.0, 1.0, 1.0, 0.0];
 
-        let camera_curve =
-            AnimatableKeyframeCurve::new(key_frames.into_iter().zip(positions)).unwrap();
         let title_curve =
             AnimatableKeyframeCurve::new(key_frames.into_iter().zip(opacities)).unwrap();
 
         let mut clip = AnimationClip::default();
-        clip.add_curve_to_target(
-            camera_id,
-            AnimatableCurve::new(animated_field!(Transform::translation), camera_curve),
-        );
         clip.add_curve_to_target(
             title_id,
             AnimatableCurve::new(SpriteAlphaProperty, title_curve),
@@ -218,9 +262,6 @@ fn setup_first_launch(
     let mut animator = AnimationPlayer::default();
     animator.play(node);
 
-    let title = asset_server.load("title.png");
-    asset_tracker.push(title.clone_weak().untyped());
-
     let first_launch = commands
         .spawn((
             FirstLaunch,
@@ -236,7 +277,7 @@ fn setup_first_launch(
         title_name,
         ChildOf(first_launch),
         Sprite {
-            image: title,
+            image: assets.title.clone(),
             color: WHITE.with_alpha(0.0).into(),
             custom_size: Some(vec2(512.0, 256.0)),
             ..default()
@@ -245,14 +286,9 @@ fn setup_first_launch(
             id:     title_id,
             player: first_launch,
         },
-        Transform::from_xyz(832.0, 1200.0, 1.0),
+        Transform::from_translation(camera_out.with_z(1.0) + Vec3::Y * 176.0),
         Visibility::default(),
     ));
-
-    commands.entity(camera).insert(AnimationTarget {
-        id:     camera_id,
-        player: first_launch,
-    });
 }
 
 fn wait_first_launch(
@@ -264,37 +300,74 @@ fn wait_first_launch(
     if animator.all_finished() {
         progress.insert(ProgressFlag::FirstLaunch);
         commands.entity(first_launch).despawn();
+        commands.remove_resource::<FirstLaunchAssets>();
         commands.set_state(TopDownState::Ready);
     }
 }
 
 fn setup_map(
     mut commands: Commands,
-    map_info: Res<MapInfo>,
-    topdown_maps: Res<TopdownMapHandles>,
-    progress: ResMut<Progress>,
+    mut map_info: ResMut<MapInfo>,
+    topdown_maps: Res<TopdownMaps>,
+    mut progress: ResMut<Progress>,
+    mut day_night: ResMut<DayNightCycle>,
+    mut camera_transform: Single<&mut Transform, With<WorldCamera>>,
 ) {
     if let Some(previous_map) = map_info.entity {
         commands.entity(previous_map).despawn();
         commands.remove_resource::<Warp>();
     };
 
-    let current_tiled_map = topdown_maps.get(progress.map);
+    let current_map = match topdown_maps.get(&progress.map) {
+        Ok(map) => map,
+        Err(error) => {
+            // Saves made before a map was removed end up on the first map
+            error!("{error}, loading \"{}\" instead", MapKey::FIRST);
+            progress.map = MapKey::first();
+            progress.spawn = None;
+            let Ok(map) = topdown_maps.get(&progress.map) else {
+                return;
+            };
+            map
+        }
+    };
+
+    info!(
+        "Loading map \"{}\" ({})",
+        current_map.info.key, current_map.info.name
+    );
+    map_info.camera_scale = current_map.info.camera_scale;
+    camera_transform.scale = Vec3::splat(current_map.info.camera_scale);
+    day_night.daylight = current_map.info.daylight;
+
     commands
-        .spawn((OnTopDown, TiledMapHandle(current_tiled_map)))
+        .spawn((OnTopDown, TiledMapHandle(current_map.handle.clone_weak())))
         .observe(setup_collider_bodies)
         .observe(setup_interactables);
 }
 
-#[derive(Debug, Default, Resource)]
+#[derive(Debug, Resource)]
 struct MapInfo {
     ready: bool,
 
     rect:         Rect,
     tilemap_size: TilemapSize,
+    // Outside of zones with their own zoom
+    camera_scale: f32,
 
-    entity:      Option<Entity>,
-    water_layer: Option<Entity>,
+    entity: Option<Entity>,
+}
+
+impl Default for MapInfo {
+    fn default() -> Self {
+        MapInfo {
+            ready:        false,
+            rect:         Rect::default(),
+            tilemap_size: TilemapSize::default(),
+            camera_scale: CAMERA_SCALE,
+            entity:       None,
+        }
+    }
 }
 
 fn reset_map_info(mut map_info: ResMut<MapInfo>) {
@@ -305,11 +378,15 @@ const Z_BETWEEN_LAYERS: f32 = 100.0;
 
 fn initialize_map_info(
     trigger: Trigger<TiledMapCreated>,
+    mut commands: Commands,
     a_tiled_maps: Res<Assets<TiledMap>>,
     q_tiled_maps: Query<(Entity, &mut TiledMapStorage), With<TiledMapMarker>>,
-    q_tiled_layers: Query<(Entity, &Name), With<TiledMapTileLayerForTileset>>,
-    mut q_tiled_objects: Query<&mut Transform, With<TiledMapObject>>,
+    mut q_tiled_objects: Query<
+        (&mut Transform, Has<EntityInteraction>, Has<TriggerZone>),
+        With<TiledMapObject>,
+    >,
     mut map_info: ResMut<MapInfo>,
+    progress: Res<Progress>,
 ) {
     let Some(tiled_map) = trigger.event().get_map_asset(&a_tiled_maps) else {
         warn!("Failed to load Tiled map asset");
@@ -321,11 +398,18 @@ fn initialize_map_info(
         return;
     };
 
-    map_storage.objects.iter().for_each(|(_tiled_id, entity)| {
-        if let Ok(mut transform) = q_tiled_objects.get_mut(*entity) {
+    map_storage.objects.iter().for_each(|(tiled_id, entity)| {
+        if let Ok((mut transform, interactable, trigger_zone)) = q_tiled_objects.get_mut(*entity) {
             // Objects higher up on the map will be given a greater negative z-offset
             let offset_y = transform.translation.y - tiled_map.rect.min.y;
             transform.translation.z -= offset_y / tiled_map.rect.height() * Z_BETWEEN_LAYERS;
+
+            if interactable || trigger_zone {
+                commands.entity(*entity).insert(InteractionId {
+                    map:    progress.map.clone(),
+                    object: *tiled_id,
+                });
+            }
         }
     });
 
@@ -335,17 +419,23 @@ fn initialize_map_info(
     map_info.tilemap_size = tiled_map.tilemap_size;
 
     map_info.entity = Some(map_entity);
-    map_info.water_layer = q_tiled_layers.iter().find_map(|(entity, name)| {
-        (name.as_str() == "TiledMapTileLayerForTileset(water, water)").then_some(entity)
-    });
 }
 
 fn setup_interactables(
     trigger: Trigger<TiledObjectCreated>,
     // mut commands: Commands,
     mut q_interactables: Query<(Entity, &EntityInteraction), With<TiledMapObject>>,
+    q_timed: Query<&TimedInteraction, With<TiledMapObject>>,
     mut dialogue_preloader: ResMut<DialoguePreload>,
 ) {
+    if let Ok(TimedInteraction {
+        interaction: EntityInteraction::Dialogue(id),
+        ..
+    }) = q_timed.get(trigger.entity)
+    {
+        dialogue_preloader.push(*id);
+    }
+
     if let Ok((_entity, EntityInteraction::Dialogue(id))) = q_interactables.get_mut(trigger.entity)
     {
         dialogue_preloader.push(*id);
@@ -360,15 +450,20 @@ fn setup_interactables(
 fn setup_collider_bodies(
     trigger: Trigger<TiledColliderCreated>,
     mut commands: Commands,
-    q_tiled_objects: Query<Option<&Warp>, With<TiledMapObject>>,
+    q_tiled_objects: Query<(Has<Warp>, Has<TriggerZone>, Has<CameraZone>), With<TiledMapObject>>,
     q_tiled_colliders: Query<&ChildOf, With<TiledColliderMarker>>,
 ) {
     if let Ok(ChildOf(parent)) = q_tiled_colliders.get(trigger.entity) {
-        if let Ok(Some(_warp)) = q_tiled_objects.get(*parent) {
-            commands
-                .entity(trigger.entity)
-                .insert((Sensor, CollisionEventsEnabled))
-                .observe(trigger_warp);
+        match q_tiled_objects.get(*parent) {
+            Ok((true, ..)) => {
+                commands
+                    .entity(trigger.entity)
+                    .insert((Sensor, CollisionEventsEnabled))
+                    .observe(trigger_warp);
+            }
+            Ok((_, true, _)) => observe_trigger_zone(&mut commands.entity(trigger.entity)),
+            Ok((.., true)) => observe_camera_zone(&mut commands.entity(trigger.entity)),
+            _ => {}
         }
     }
 
@@ -390,56 +485,47 @@ fn wait_for_ready(
     }
 }
 
-const TOTAL_TOPDOWN_MAPS: usize = 7;
-
-use serde::{Deserialize, Serialize};
-#[derive(Clone, Copy, Debug, Default, Reflect, Serialize, Deserialize)]
-#[reflect(Default)]
-pub enum TopdownMapIndex {
-    #[default]
-    Mountain,
-    Backyard,
-    Fields,
-    Forest,
-    Buckotown,
-    Farm,
-    Beach,
+#[derive(Debug, Clone, Component, Resource, Reflect, Default)]
+#[reflect(Component, Default)]
+struct Warp {
+    target_map:         MapKey,
+    // Name of a SpawnPoint on the target map, offset_or_point is used if empty
+    target_spawn:       String,
+    point_mode:         bool,
+    offset_or_point:    Vec2,
+    // Flag needed to go through, ProgressFlag::None for none
+    required_flag:      ProgressFlag,
+    // Require the flag to be missing instead, e.g. a door that closes behind you
+    flag_absent:        bool,
+    // Time of day it can be gone through, TimeOfDay::Any for always
+    required_time:      TimeOfDay,
+    // Played instead of warping while locked, a generic message if None
+    locked_interaction: Option<EntityInteraction>,
 }
 
-#[derive(Debug, Resource)]
-struct TopdownMapHandles([Handle<TiledMap>; TOTAL_TOPDOWN_MAPS]);
-
-impl TopdownMapHandles {
-    fn get(&self, index: TopdownMapIndex) -> Handle<TiledMap> {
-        // SAFETY: ['TopDownMapIndex'] varient count MUST match ['TOTAL_TOPDOWN_MAPS']
-        unsafe { self.0.get_unchecked(index as usize).clone_weak() }
+impl Warp {
+    fn unlocked(&self, progress: &Progress) -> bool {
+        let flag_met = self.required_flag == ProgressFlag::None
+            || progress.contains(&self.required_flag) != self.flag_absent;
+        flag_met && self.required_time.includes(progress.time_of_day())
     }
 }
 
-impl FromWorld for TopdownMapHandles {
-    fn from_world(world: &mut World) -> Self {
-        const MAP_PATHS: [&str; TOTAL_TOPDOWN_MAPS] = [
-            "maps/mountains.tmx",
-            "maps/backyard.tmx",
-            "maps/fields.tmx",
-            "maps/forest.tmx",
-            "maps/buckotown.tmx",
-            "maps/farm.tmx",
-            "maps/beach.tmx",
-        ];
-
-        TopdownMapHandles(
-            MAP_PATHS.map(|path| world.resource::<AssetServer>().load::<TiledMap>(path)),
-        )
-    }
+// A named place the player can be put at, placed as a Tiled object
+#[derive(Debug, Clone, Component, Reflect, Default)]
+#[reflect(Component, Default)]
+struct SpawnPoint {
+    name: String,
 }
 
-#[derive(Debug, Clone, Copy, Component, Resource, Reflect, Default)]
-#[reflect(Component, Default)]
-struct Warp {
-    target_map:      TopdownMapIndex,
-    point_mode:      bool,
-    offset_or_point: Vec2,
+// Positions of the SpawnPoints of every map created so far
+#[derive(Debug, Default, Resource)]
+pub struct SpawnPoints(HashMap<(MapKey, String), Vec2>);
+
+impl SpawnPoints {
+    pub fn get(&self, map: &MapKey, name: &str) -> Option<Vec2> {
+        self.0.get(&(map.clone(), name.to_string())).copied()
+    }
 }
 
 fn trigger_warp(
@@ -447,21 +533,51 @@ fn trigger_warp(
     player: Single<Entity, With<Player>>,
     q_tiled_colliders: Query<&ChildOf, With<TiledColliderMarker>>,
     q_tiled_objects: Query<&Warp, With<TiledMapObject>>,
+    interaction_state: Res<State<InteractionState>>,
+    topdown_maps: Res<TopdownMaps>,
+    progress: Res<Progress>,
     mut commands: Commands,
 ) {
+    const LOCKED_TEXT: &str = "warp.locked";
+
     if trigger.collider != *player {
         // Something else it trying to warp
         return;
     }
 
-    if let Ok(ChildOf(parent)) = q_tiled_colliders.get(trigger.target()) {
-        if let Ok(warp) = q_tiled_objects.get(*parent) {
-            commands.insert_resource(*warp);
-            commands.set_state(TopDownState::Warping);
-        }
+    let Ok(ChildOf(parent)) = q_tiled_colliders.get(trigger.target()) else {
+        return;
+    };
+    let Ok(warp) = q_tiled_objects.get(*parent) else {
+        return;
+    };
+
+    if !topdown_maps.contains(&warp.target_map) {
+        error!("Warp leads to unknown map \"{}\"", warp.target_map);
+        return;
+    }
+
+    if warp.unlocked(&progress) {
+        commands.insert_resource(warp.clone());
+        commands.set_state(TopDownState::Warping);
+        return;
+    }
+
+    if *interaction_state.get() == InteractionState::None {
+        let locked_interaction = warp
+            .locked_interaction
+            .clone()
+            .unwrap_or(EntityInteraction::Text(LOCKED_TEXT.to_string()));
+        commands.run_system_cached_with(play_interactions, Some(locked_interaction));
     }
 }
 
+fn iris_to_warp(mut commands: Commands, player: Single<Entity, With<Player>>) {
+    commands
+        .transition(Transition::cover(TransitionKind::Iris, Color::BLACK).focus(*player))
+        .observe(after_transition(warp_player));
+}
+
 fn warp_player(
     warp: Res<Warp>,
     mut progress: ResMut<Progress>,
@@ -477,20 +593,69 @@ fn warp_player(
         false => player_transform.translation += warp.offset_or_point.extend(0.0),
     };
 
+    // Takes over from the offset once the target map is created
+    if !warp.target_spawn.is_empty() {
+        progress.spawn = Some(warp.target_spawn.clone());
+    }
+
     camera_transform.translation = player_transform.translation;
 
     let Vec3 { x, y, z } = player_transform.translation;
     info!(
-        "Warping player to {:?} ({}, {}, {})",
+        "Warping player to {} ({}, {}, {})",
         warp.target_map, x, y, z
     );
 
-    progress.map = warp.target_map;
+    progress.map = warp.target_map.clone();
     progress.position = player_transform.translation.xy();
 
     topdown_state.set(TopDownState::Loading);
 }
 
+// Records the SpawnPoints of a created map, then moves the player to
+// the spawn point set in Progress if there is one
+fn place_player_at_spawn(
+    trigger: Trigger<TiledMapCreated>,
+    q_tiled_maps: Query<&TiledMapStorage, With<TiledMapMarker>>,
+    q_spawn_points: Query<(&SpawnPoint, &Transform), With<TiledMapObject>>,
+    mut spawn_points: ResMut<SpawnPoints>,
+    mut progress: ResMut<Progress>,
+    mut player_transform: Single<
+        &mut Transform,
+        (With<Player>, Without<WorldCamera>, Without<SpawnPoint>),
+    >,
+    mut camera_transform: Single<&mut Transform, (With<WorldCamera>, Without<SpawnPoint>)>,
+) {
+    let Ok(map_storage) = q_tiled_maps.get(trigger.entity) else {
+        return;
+    };
+
+    let map = progress.map.clone();
+    map_storage
+        .objects
+        .values()
+        .filter_map(|entity| q_spawn_points.get(*entity).ok())
+        .for_each(|(spawn_point, transform)| {
+            spawn_points.0.insert(
+                (map.clone(), spawn_point.name.clone()),
+                transform.translation.xy(),
+            );
+        });
+
+    let Some(spawn) = progress.spawn.take() else {
+        return;
+    };
+    let Some(position) = spawn_points.get(&map, &spawn) else {
+        warn!("No SpawnPoint \"{spawn}\" on {map:?}, keeping the last position");
+        return;
+    };
+
+    info!("Placing player at SpawnPoint \"{spawn}\"");
+    player_transform.translation = position.extend(player_transform.translation.z);
+    camera_transform.translation = player_transform.translation;
+    progress.position = position;
+}
+
 fn update_player_z(
     mut player_transform: Single<&mut Transform, (With<Player>, Changed<Transform>)>,
     map_info: Res<MapInfo>,
@@ -500,98 +665,80 @@ fn update_player_z(
         -offset_y / map_info.rect.height() * Z_BETWEEN_LAYERS - Z_BETWEEN_LAYERS;
 }
 
-#[derive(Debug, Event)]
-struct HopUpdate;
-
-#[derive(Debug, Default, Component, Clone, Copy, PartialEq, Reflect)]
-enum HopState {
-    Ready,
-    Charging,
-    Airborne,
-    Landing,
-    Landed,
-    #[default]
-    Idle,
-}
-
-impl HopState {
-    fn cycle_state(&mut self) {
-        *self = match self {
-            HopState::Ready => HopState::Charging,
-            HopState::Charging => HopState::Airborne,
-            HopState::Airborne => HopState::Landing,
-            HopState::Landing => HopState::Landed,
-            HopState::Landed => HopState::Idle,
-            HopState::Idle => HopState::Idle,
-        };
-    }
+// Hops are a cycle of clips, started by moving and interrupted by water
+fn player_animator() -> SpriteAnimator {
+    SpriteAnimator::new("idle")
+        .with_any_transition("swim", [AnimatorCondition::Is("submerged")])
+        .with_state(
+            "idle",
+            AnimatorState::new("hop_ready")
+                .directional()
+                .to("charge", [AnimatorCondition::Is("moving")]),
+        )
+        .with_state(
+            "charge",
+            AnimatorState::new("hop_charge")
+                .directional()
+                .to("airborne", [AnimatorCondition::Finished]),
+        )
+        .with_state(
+            "airborne",
+            AnimatorState::new("hop_airborne")
+                .directional()
+                .to("landing", [AnimatorCondition::Finished]),
+        )
+        .with_state(
+            "landing",
+            AnimatorState::new("hop_landing")
+                .directional()
+                .to("idle", [AnimatorCondition::Finished]),
+        )
+        .with_state(
+            "swim",
+            AnimatorState::new("swim")
+                .turning()
+                .to("idle", [AnimatorCondition::Not("submerged")]),
+        )
 }
 
-fn player_hop(
-    mut commands: Commands,
+fn update_player_animator(
+    player: Single<(&mut SpriteAnimator, &Submerged), With<Player>>,
     user_input: Res<UserInput>,
-    player: Single<(Entity, &mut HopState), With<Player>>,
+    movement_enabled: Option<Res<State<MovementEnabled>>>,
 ) {
-    let (entity, mut hop_state) = player.into_inner();
+    let (mut animator, submerged) = player.into_inner();
 
-    if *hop_state == HopState::Idle && user_input.moving() {
-        *hop_state = HopState::Ready;
-        commands.trigger_targets(HopUpdate, entity);
+    let moving = movement_enabled.is_some() && user_input.moving();
+    animator.set_bool("moving", moving);
+    animator.set_bool("submerged", submerged.0);
+    if moving {
+        animator.set_direction(user_input.last_valid_direction);
     }
 }
 
-fn update_player_animations(
-    trigger: Trigger<SpriteAnimationFinished>,
+fn player_hop(
+    trigger: Trigger<AnimatorClipChanged>,
     mut commands: Commands,
-    player: Single<(&mut HopState, Ref<Submerged>), With<Player>>,
+    player: Single<(&PlayerSurface, &mut LinearVelocity), With<Player>>,
+    user_input: Res<UserInput>,
 ) {
-    let (mut hop_state, submerged) = player.into_inner();
+    const HOP_IMPULSE: f32 = 128.0;
 
-    if submerged.is_changed() {
-        *hop_state = HopState::Idle;
-        commands.trigger_targets(SplashUpdate, trigger.target());
+    let (surface, mut velocity) = player.into_inner();
+
+    if trigger.entered("airborne") {
+        let impulse = HOP_IMPULSE * surface.hop_scale();
+        *velocity = LinearVelocity(user_input.last_valid_direction * impulse);
     }
 
-    hop_state.cycle_state();
-    commands.trigger_targets(HopUpdate, trigger.target());
+    if trigger.entered("swim") || trigger.exited("swim") {
+        commands.trigger_targets(SurfaceImpact, trigger.target());
+    }
 }
 
-fn update_player_hop(
-    _trigger: Trigger<HopUpdate>,
-    player: Single<(&HopState, &mut SpriteAnimation, &mut LinearVelocity), With<Player>>,
-    user_input: Res<UserInput>,
-    mut index_offset: Local<usize>,
-) {
-    const HOP_IMPULSE: f32 = 128.0;
-    const SPRITES_PER_ROW: usize = 8;
-
-    let (hop_state, mut animation, mut velocity) = player.into_inner();
-
-    match hop_state {
-        HopState::Ready => {
-            // Map direction to spritesheet y-offset
-            *index_offset = match user_input.last_valid_direction {
-                Dir2::NORTH | Dir2::SOUTH => *index_offset,
-                Dir2::EAST | Dir2::NORTH_EAST | Dir2::SOUTH_EAST => 0,
-                Dir2::WEST | Dir2::NORTH_WEST | Dir2::SOUTH_WEST => SPRITES_PER_ROW,
-                _ => unreachable!(),
-            };
-            *animation = SpriteAnimation::set_frame(*index_offset);
-        }
-        HopState::Charging => {
-            *animation = SpriteAnimation::new(1 + *index_offset, 2 + *index_offset, 12);
-        }
-        HopState::Airborne => {
-            *velocity = LinearVelocity(user_input.last_valid_direction * HOP_IMPULSE);
-            *animation = SpriteAnimation::new(3 + *index_offset, 5 + *index_offset, 12);
-        }
-        HopState::Landing => {
-            *animation = SpriteAnimation::new(6 + *index_offset, 7 + *index_offset, 12);
-        }
-        HopState::Landed => {
-            *animation = SpriteAnimation::set_frame(*index_offset);
-        }
-        HopState::Idle => {}
+fn player_frame_events(trigger: Trigger<SpriteAnimationEvent>, mut commands: Commands) {
+    if trigger.name == "land" {
+        commands.trigger_targets(SurfaceImpact, trigger.target());
     }
 }
 
@@ -603,37 +750,6 @@ struct WaterTile;
 #[reflect(Default, Component)]
 struct Submerged(bool);
 
-#[derive(Debug, Event)]
-struct SplashUpdate;
-
-fn update_player_submerged(
-    player: Single<(&Transform, &mut Submerged), (With<Player>, Changed<Transform>)>,
-    map_info: Res<MapInfo>,
-    q_tiled_layers: Query<&TileStorage, With<TiledMapTileLayerForTileset>>,
-    q_water_tiles: Query<&WaterTile, With<TiledMapTile>>,
-) {
-    let (transform, mut submerged) = player.into_inner();
-
-    let Some(player_tile_pos) = get_tile_pos(transform.translation, &map_info.tilemap_size) else {
-        debug_once!("Player outside map");
-        return;
-    };
-
-    let Some(water_layer_entity) = map_info.water_layer else {
-        debug_once!("Current map has no water layer");
-        return;
-    };
-
-    let Ok(water_tile_storage) = q_tiled_layers.get(water_layer_entity) else {
-        warn_once!("Failed to get tile storage for current map's water layer");
-        return;
-    };
-
-    if let Some(current_tile) = water_tile_storage.get(&player_tile_pos) {
-        submerged.set_if_neq(Submerged(q_water_tiles.contains(current_tile)));
-    }
-}
-
 // fn player_splash(
 //     player: Single<(&Submerged, &mut SpriteAnimation), (With<Player>, Changed<Submerged>)>,
 // ) {
@@ -641,33 +757,24 @@ fn update_player_submerged(
 // }
 
 fn player_swim(
-    player: Single<(Ref<Submerged>, &mut SpriteAnimation, &mut LinearVelocity), With<Player>>,
+    mut commands: Commands,
+    player: Single<(Entity, &mut LinearVelocity), With<Player>>,
     user_input: Res<UserInput>,
-    mut index_offset: Local<usize>,
+    time: Res<Time>,
+    mut stroke_time: Local<f32>,
 ) {
-    const SWIM_EAST_OFFSET: usize = 16;
-    const SWIM_WEST_OFFSET: usize = 20;
-
-    if *index_offset == 0 {
-        *index_offset = SWIM_EAST_OFFSET;
-    }
-
-    let (submerged, mut sprite_animation, mut player_velocity) = player.into_inner();
-
-    let new_offset = match user_input.last_valid_direction {
-        Dir2::NORTH | Dir2::SOUTH => *index_offset,
-        Dir2::NORTH_EAST | Dir2::SOUTH_EAST | Dir2::EAST => SWIM_EAST_OFFSET,
-        Dir2::NORTH_WEST | Dir2::SOUTH_WEST | Dir2::WEST => SWIM_WEST_OFFSET,
-        _ => unreachable!(),
-    };
+    const STROKE_DURATION: f32 = 0.5; // one swim cycle, 4 frames at 8 fps
 
-    if new_offset != *index_offset || submerged.is_changed() {
-        *sprite_animation = SpriteAnimation::new(new_offset, new_offset + 3, 8).looping();
-        *index_offset = new_offset
-    }
+    let (entity, mut player_velocity) = player.into_inner();
 
     if user_input.moving() {
         *player_velocity = LinearVelocity(user_input.last_valid_direction * 32.0);
+
+        *stroke_time += time.delta_secs();
+        if *stroke_time >= STROKE_DURATION {
+            *stroke_time -= STROKE_DURATION;
+            commands.trigger_targets(SurfaceImpact, entity);
+        }
     }
 }
 
@@ -676,25 +783,63 @@ fn player_submerged(player_submerged: Single<&Submerged, With<Player>>) -> bool
 }
 
 fn update_near_interactables(
-    q_interactables: Query<(Entity, &Transform), (With<EntityInteraction>, Without<Player>)>,
+    q_interactables: Query<
+        (
+            Entity,
+            &Transform,
+            Option<&InteractionId>,
+            Has<FollowUpInteraction>,
+            Option<&TimedInteraction>,
+            Option<&Presence>,
+        ),
+        (With<EntityInteraction>, Without<Player>),
+    >,
     player: Single<(&mut InteractTarget, &Transform), (With<Player>, Changed<Transform>)>,
+    user_input: Res<UserInput>,
+    progress: Res<Progress>,
 ) {
     const INTERACTION_RANGE: u32 = 32; // u32 to get a consistent value after squaring
     const RANGE_SQUARED: f32 = INTERACTION_RANGE.pow(2) as f32;
+    // Cosine of the widest angle from the facing direction that still counts as facing
+    const FACING_COS: f32 = 0.5;
 
     let (mut interaction_target, player_transform) = player.into_inner();
     let player_position = player_transform.translation.truncate();
-
-    let new_interaction_target =
-        q_interactables
-            .iter()
-            .find_map(|(entity, interactable_transform)| {
-                let interactable_position = interactable_transform.translation.truncate();
-                let entity_in_range =
-                    interactable_position.distance_squared(player_position) < RANGE_SQUARED;
-
-                entity_in_range.then_some(entity)
-            });
+    let facing = user_input.last_valid_direction;
+
+    let time_of_day = progress.time_of_day();
+
+    // Interactables being faced come first, then the nearest one is picked
+    let new_interaction_target = q_interactables
+        .iter()
+        .filter(|(.., presence)| presence.is_none_or(|presence| presence.present(&progress)))
+        .filter(|(_, _, interaction_id, has_follow_up, timed, _)| {
+            // Consumed interactions with nothing to follow up on are done for good,
+            // unless it's the time of day for another one
+            *has_follow_up
+                || timed.is_some_and(|timed| timed.time.includes(time_of_day))
+                || !interaction_id.is_some_and(|id| progress.interaction_consumed(id))
+        })
+        .filter_map(|(entity, interactable_transform, ..)| {
+            let offset = interactable_transform.translation.truncate() - player_position;
+            let distance_squared = offset.length_squared();
+            let is_facing = offset
+                .try_normalize()
+                .is_none_or(|direction| direction.dot(*facing) >= FACING_COS);
+
+            (distance_squared < RANGE_SQUARED).then_some((entity, !is_facing, distance_squared))
+        })
+        .min_by(|(_, a_behind, a_distance), (_, b_behind, b_distance)| {
+            a_behind
+                .cmp(b_behind)
+                .then(a_distance.total_cmp(b_distance))
+        })
+        .map(|(entity, ..)| entity);
+
+    // Only touch the target when it changes so the prompt isn't rebuilt every step
+    if interaction_target.get().copied() == new_interaction_target {
+        return;
+    }
 
     match new_interaction_target {
         Some(entity) => interaction_target.set(entity),
@@ -702,6 +847,44 @@ fn update_near_interactables(
     }
 }
 
+fn update_interact_prompt(
+    mut commands: Commands,
+    player: Single<&InteractTarget, (With<Player>, Changed<InteractTarget>)>,
+    q_prompts: Query<Entity, With<InteractPrompt>>,
+    mut q_highlighted: Query<(Entity, &mut Sprite, &Highlighted)>,
+    mut q_sprites: Query<&mut Sprite, (Without<Highlighted>, Without<Player>)>,
+    settings: Res<Persistent<Settings>>,
+    font: Res<StandardFont>,
+) {
+    const PROMPT_OFFSET: Vec3 = vec3(0.0, 24.0, Z_EFFECTS);
+    const PROMPT_FONT_SIZE: f32 = 8.0;
+
+    q_prompts.iter().for_each(|prompt| {
+        commands.entity(prompt).despawn();
+    });
+    q_highlighted
+        .iter_mut()
+        .for_each(|(entity, mut sprite, Highlighted(color))| {
+            sprite.color = *color;
+            commands.entity(entity).remove::<Highlighted>();
+        });
+
+    let Some(&target) = player.get() else {
+        return;
+    };
+
+    commands.spawn((
+        ChildOf(target),
+        interact_prompt(&settings, &font, PROMPT_FONT_SIZE, PROMPT_OFFSET),
+        RENDER_LAYER_WORLD,
+    ));
+
+    if let Ok(mut sprite) = q_sprites.get_mut(target) {
+        commands.entity(target).insert(Highlighted(sprite.color));
+        sprite.color = HIGHLIGHT_COLOR;
+    }
+}
+
 fn _over_interactables(
     over: Trigger<Pointer<Over>>,
     q_interactables: Query<Entity, With<EntityInteraction>>,
@@ -724,40 +907,50 @@ fn _out_interactables(
 }
 
 fn get_topdown_interactions(
-    player: Single<(&InteractTarget, &Transform), With<Player>>,
-    q_interactables: Query<(&mut EntityInteraction, &Transform)>,
+    player: Single<&InteractTarget, With<Player>>,
+    q_interactables: Query<(
+        &EntityInteraction,
+        Option<&InteractionId>,
+        Option<&FollowUpInteraction>,
+        Option<&TimedInteraction>,
+        Option<&Presence>,
+    )>,
+    mut progress: ResMut<Progress>,
 ) -> Option<EntityInteraction> {
-    // const INTERACTION_RANGE: f32 = 32.0;
+    let target_entity = player.get()?;
 
-    let (interaction_target, _player_transform) = player.into_inner();
-    let target_entity = interaction_target.as_ref()?;
+    let (entity_interaction, interaction_id, follow_up, timed, presence) =
+        q_interactables.get(*target_entity).ok()?;
 
-    let (mut entity_interaction, _target_transform) =
-        q_interactables.get_inner(*target_entity).ok()?;
-
-    // let player_in_range = player_transform
-    //     .translation
-    //     .truncate()
-    //     .distance(target_transform.translation.truncate())
-    //     < INTERACTION_RANGE;
+    // The target isn't updated while standing still, so it may have left since
+    if presence.is_some_and(|presence| !presence.present(&progress)) {
+        return None;
+    }
 
-    // player_in_range.then_some(entity_interaction.clone())
+    if let Some(timed) = timed.filter(|timed| timed.time.includes(progress.time_of_day())) {
+        return Some(timed.interaction.clone());
+    }
 
-    let entity_interaction = match entity_interaction.as_mut() {
-        EntityInteraction::Dialogue(dialogue_id) => {
-            EntityInteraction::Dialogue(std::mem::take(dialogue_id))
-        }
-        _ => entity_interaction.clone(),
+    // Interactions without an id weren't placed through Tiled and always replay
+    let Some(id) = interaction_id.filter(|_| entity_interaction.one_shot(follow_up)) else {
+        return Some(entity_interaction.clone());
     };
 
-    Some(entity_interaction)
+    match progress.consume_interaction(id.clone()) {
+        false => Some(entity_interaction.clone()),
+        true => follow_up.map(|FollowUpInteraction(follow_up)| follow_up.clone()),
+    }
 }
 
+// Yields to any camera sequence playing
 fn camera_system(
-    mut camera_transform: Single<&mut Transform, With<WorldCamera>>,
+    camera_transform: Single<&mut Transform, (With<WorldCamera>, Without<CameraSequencePlayer>)>,
     player: Single<&Transform, (With<Player>, Without<WorldCamera>)>,
     user_input: Res<UserInput>,
     map_info: Res<MapInfo>,
+    camera_zones: Res<CameraZones>,
+    q_zones: Query<&CameraZone>,
+    q_bounds: Query<&ColliderAabb>,
     mut stopwatch: Local<Stopwatch>,
     mut target_direction: Local<Vec2>,
     time: Res<Time>,
@@ -767,11 +960,24 @@ fn camera_system(
     const HALF_TILE_SIZE: f32 = 16.0;
     // Percentage of the view size the camera will target
     const LOOKAHEAD: f32 = 0.4;
-    // Percentage of distance the camera will move to the target per render frame
-    const SMOOTH: f32 = 0.01;
+    // Decay rates of the distance left to the target, 0.6 is about 1% per frame at 60fps
+    const POSITION_DECAY: f32 = 0.6;
+    const ZOOM_DECAY: f32 = 2.0;
     const NEW_DIRECTION_DELAY: f32 = 1.0;
 
-    let view_size = WINDOW_SIZE * camera_transform.scale.xy();
+    let mut camera_transform = camera_transform.into_inner();
+    let zone = camera_zones.current(&q_zones, &q_bounds);
+
+    let mut scale = camera_transform.scale.x;
+    let target_scale = match zone {
+        Some((zone, _)) if zone.zoom > 0.0 => zone.zoom,
+        _ => map_info.camera_scale,
+    };
+    scale.smooth_nudge(&target_scale, ZOOM_DECAY, time.delta_secs());
+    camera_transform.scale = Vec3::splat(scale);
+
+    // Targets are worked out for the view at the zone's zoom, not the one mid-blend
+    let view_size = WINDOW_SIZE * target_scale;
 
     if *target_direction != user_input.raw_vector
         && stopwatch.tick(time.delta()).elapsed_secs() > NEW_DIRECTION_DELAY
@@ -780,17 +986,32 @@ fn camera_system(
         *target_direction = user_input.raw_vector;
     }
 
-    let camera_offset = (view_size * LOOKAHEAD * *target_direction).extend(0.0);
-    let camera_target = player.translation + camera_offset;
+    let follow = player.translation.truncate() + view_size * LOOKAHEAD * *target_direction;
+    let camera_target = match zone {
+        Some((zone, bounds)) => zone.rule.target(follow, bounds, view_size),
+        None => follow,
+    };
 
     // Bounds camera from rendering the void outside the current map
-    let camera_min = (map_info.rect.min + (view_size / 2.0) - HALF_TILE_SIZE).extend(f32::MIN);
-    let camera_max = (map_info.rect.max - (view_size / 2.0) - HALF_TILE_SIZE).extend(f32::MAX);
+    let camera_target = clamp_view(
+        camera_target,
+        map_info.rect.min + (view_size / 2.0) - HALF_TILE_SIZE,
+        map_info.rect.max - (view_size / 2.0) - HALF_TILE_SIZE,
+    )
+    .extend(camera_transform.translation.z);
 
-    camera_transform.translation = camera_transform
+    camera_transform
         .translation
-        .lerp(camera_target, SMOOTH)
-        .clamp(camera_min, camera_max);
+        .smooth_nudge(&camera_target, POSITION_DECAY, time.delta_secs());
+
+    // The view mid-zoom can't show the void either
+    let view_size = WINDOW_SIZE * scale;
+    camera_transform.translation = clamp_view(
+        camera_transform.translation.truncate(),
+        map_info.rect.min + (view_size / 2.0) - HALF_TILE_SIZE,
+        map_info.rect.max - (view_size / 2.0) - HALF_TILE_SIZE,
+    )
+    .extend(camera_transform.translation.z);
 }
 
 fn get_tile_pos(position: Vec3, tile_map_size: &TilemapSize) -> Option<TilePos> {
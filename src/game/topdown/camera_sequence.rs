@@ -0,0 +1,296 @@
+use bevy::asset::{AssetLoader, LoadContext, io::Reader};
+use serde::Deserialize;
+use thiserror::Error;
+
+use super::*;
+
+pub fn topdown_camera_sequence_plugin(app: &mut App) {
+    app.add_special_interaction("camera_sequence", camera_sequence_special)
+        .add_systems(
+            Update,
+            play_camera_sequences
+                .run_if(in_state(TopDownState::Ready).or(in_state(TopDownState::FirstLaunch))),
+        )
+        .add_observer(start_camera_sequence)
+        .init_asset::<CameraSequence>()
+        .init_asset_loader::<CameraSequenceLoader>();
+}
+
+// Played when a sequence doesn't end by returning to the player itself
+const DEFAULT_RETURN: f32 = 1.5; // seconds
+
+// Path of a .camera.ron to play on the topdown camera, replacing the one playing
+#[derive(Debug, Clone, Event)]
+pub struct PlayCameraSequence(pub String);
+
+#[derive(Debug, Clone, Deserialize)]
+pub enum CameraTarget {
+    Point(Vec2),
+    // Object with this Name, e.g. one placed in Tiled
+    Object(String),
+    Player,
+    // Offset from the player, e.g. to look at something above them
+    PlayerOffset(Vec2),
+}
+
+fn smooth_step() -> EaseFunction {
+    EaseFunction::SmoothStep
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub enum CameraStep {
+    // Follows the target if it moves while panning
+    Pan {
+        to:       CameraTarget,
+        duration: f32,
+        #[serde(default = "smooth_step")]
+        ease:     EaseFunction,
+    },
+    // Seconds to stay put
+    Hold(f32),
+    // Scale of the camera's Transform, the topdown camera sits at 0.5
+    Zoom {
+        scale:    f32,
+        duration: f32,
+        #[serde(default = "smooth_step")]
+        ease:     EaseFunction,
+    },
+    // Pans back to the player at the scale the sequence started with
+    Return {
+        duration: f32,
+        #[serde(default = "smooth_step")]
+        ease:     EaseFunction,
+    },
+}
+
+impl CameraStep {
+    fn duration(&self) -> f32 {
+        match *self {
+            CameraStep::Pan { duration, .. }
+            | CameraStep::Zoom { duration, .. }
+            | CameraStep::Return { duration, .. } => duration,
+            CameraStep::Hold(duration) => duration,
+        }
+    }
+
+    fn ease(&self) -> EaseFunction {
+        match *self {
+            CameraStep::Pan { ease, .. }
+            | CameraStep::Zoom { ease, .. }
+            | CameraStep::Return { ease, .. } => ease,
+            CameraStep::Hold(_) => EaseFunction::Linear,
+        }
+    }
+}
+
+// Steps played in order on the topdown camera, loaded from a .camera.ron file
+#[derive(Debug, Asset, TypePath, Deserialize)]
+pub struct CameraSequence {
+    steps: Vec<CameraStep>,
+}
+
+impl CameraSequence {
+    // Sequences always end back on the player, with an extra Return if needed
+    fn step(&self, index: usize) -> Option<CameraStep> {
+        let returned = matches!(self.steps.last(), Some(CameraStep::Return { .. }));
+        match self.steps.get(index) {
+            Some(step) => Some(step.clone()),
+            None if index == self.steps.len() && !returned => Some(CameraStep::Return {
+                duration: DEFAULT_RETURN,
+                ease:     smooth_step(),
+            }),
+            None => None,
+        }
+    }
+}
+
+// Takes the camera away from camera_system until the sequence is over,
+// which then blends back from wherever the sequence left it
+#[derive(Debug, Component)]
+pub struct CameraSequencePlayer {
+    sequence:   Handle<CameraSequence>,
+    step:       usize,
+    // None until the current step has started
+    timer:      Option<Timer>,
+    from:       Vec3,
+    from_scale: f32,
+    home_scale: f32,
+}
+
+fn camera_sequence_special(In(args): In<SpecialArgs>, mut commands: Commands) {
+    match args.first() {
+        Some(path) => commands.trigger(PlayCameraSequence(path.clone())),
+        None => warn!("camera_sequence needs the path of a .camera.ron"),
+    }
+}
+
+fn start_camera_sequence(
+    trigger: Trigger<PlayCameraSequence>,
+    mut commands: Commands,
+    camera: Single<(Entity, &Transform, Option<&CameraSequencePlayer>), With<WorldCamera>>,
+    topdown_state: Option<Res<State<TopDownState>>>,
+    asset_server: Res<AssetServer>,
+) {
+    let PlayCameraSequence(path) = trigger.event();
+    if topdown_state.is_none() {
+        warn!("Camera sequence \"{path}\" played outside of TopDown");
+        return;
+    }
+
+    let (camera, transform, playing) = camera.into_inner();
+    // A sequence cut short by another still returns to the original zoom
+    let home_scale = playing.map_or(transform.scale.x, |playing| playing.home_scale);
+
+    commands.entity(camera).insert(CameraSequencePlayer {
+        sequence: asset_server.load(path),
+        step: 0,
+        timer: None,
+        from: transform.translation,
+        from_scale: transform.scale.x,
+        home_scale,
+    });
+}
+
+fn play_camera_sequences(
+    mut commands: Commands,
+    camera: Single<(Entity, &mut CameraSequencePlayer, &mut Transform), With<WorldCamera>>,
+    player: Single<&Transform, (With<Player>, Without<WorldCamera>)>,
+    q_named: Query<(&Name, &GlobalTransform)>,
+    movement: Option<Res<State<MovementEnabled>>>,
+    interaction_state: Res<State<InteractionState>>,
+    topdown_state: Res<State<TopDownState>>,
+    sequences: Res<Assets<CameraSequence>>,
+    asset_server: Res<AssetServer>,
+    time: Res<Time>,
+) {
+    let (camera, mut sequence_player, mut transform) = camera.into_inner();
+
+    // Whatever started the sequence may hand movement back while it plays
+    if movement.is_some() {
+        commands.run_system_cached(disable_movement);
+    }
+
+    let step = match sequences.get(&sequence_player.sequence) {
+        Some(sequence) => sequence.step(sequence_player.step),
+        None if asset_server
+            .load_state(&sequence_player.sequence)
+            .is_failed() =>
+        {
+            error!("Camera sequence failed to load, skipping it");
+            None
+        }
+        None => return,
+    };
+
+    let Some(step) = step else {
+        commands.entity(camera).remove::<CameraSequencePlayer>();
+        // The first launch hands movement back itself once it's Ready
+        if *interaction_state.get() == InteractionState::None
+            && *topdown_state.get() == TopDownState::Ready
+        {
+            commands.run_system_cached(enable_movement);
+        }
+        return;
+    };
+
+    if sequence_player.timer.is_none() {
+        sequence_player.timer = Some(Timer::from_seconds(step.duration(), TimerMode::Once));
+        sequence_player.from = transform.translation;
+        sequence_player.from_scale = transform.scale.x;
+    }
+
+    let Some(timer) = sequence_player.timer.as_mut() else {
+        return;
+    };
+    let finished = timer.tick(time.delta()).finished();
+    let t = step.ease().sample_clamped(timer.fraction());
+
+    let from = sequence_player.from;
+    let pan_to = |target: Vec2| from.lerp(target.extend(from.z), t);
+
+    match step {
+        CameraStep::Pan { to, .. } => {
+            let target = match to {
+                CameraTarget::Point(point) => Some(point),
+                CameraTarget::Object(name) => {
+                    let target = q_named.iter().find_map(|(object, transform)| {
+                        (object.as_str() == name).then(|| transform.translation().truncate())
+                    });
+                    if target.is_none() {
+                        warn_once!("Camera sequence object \"{name}\" not found");
+                    }
+                    target
+                }
+                CameraTarget::Player => Some(player.translation.truncate()),
+                CameraTarget::PlayerOffset(offset) => Some(player.translation.truncate() + offset),
+            };
+            if let Some(target) = target {
+                transform.translation = pan_to(target);
+            }
+        }
+        CameraStep::Hold(_) => {}
+        CameraStep::Zoom { scale, .. } => {
+            transform.scale = Vec3::splat(sequence_player.from_scale.lerp(scale, t));
+        }
+        CameraStep::Return { .. } => {
+            transform.translation = pan_to(player.translation.truncate());
+            let scale = sequence_player
+                .from_scale
+                .lerp(sequence_player.home_scale, t);
+            transform.scale = Vec3::splat(scale);
+        }
+    }
+
+    if finished {
+        sequence_player.step += 1;
+        sequence_player.timer = None;
+    }
+}
+
+#[derive(Default)]
+struct CameraSequenceLoader;
+
+/// Possible errors that can be produced by [`CameraSequenceLoader`]
+#[non_exhaustive]
+#[derive(Debug, Error)]
+enum CameraSequenceLoaderError {
+    /// An [IO](std::io) Error
+    #[error("Could not load file: {0}")]
+    Io(#[from] std::io::Error),
+    /// A [RON](ron) Error
+    #[error("Could not parse camera sequence: {0}")]
+    Ron(#[from] bevy::scene::ron::de::SpannedError),
+    /// A step Timer can't be made from
+    #[error("Step {0} has a negative or infinite duration")]
+    InvalidDuration(usize),
+}
+
+impl AssetLoader for CameraSequenceLoader {
+    type Asset = CameraSequence;
+    type Settings = ();
+    type Error = CameraSequenceLoaderError;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &(),
+        _load_context: &mut LoadContext<'_>,
+    ) -> Result<Self::Asset, Self::Error> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+        let sequence: CameraSequence = bevy::scene::ron::de::from_bytes(&bytes)?;
+
+        match sequence
+            .steps
+            .iter()
+            .position(|step| step.duration() < 0.0 || !step.duration().is_finite())
+        {
+            Some(index) => Err(CameraSequenceLoaderError::InvalidDuration(index)),
+            None => Ok(sequence),
+        }
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["camera.ron"]
+    }
+}
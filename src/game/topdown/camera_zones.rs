@@ -0,0 +1,129 @@
+use super::*;
+
+pub fn topdown_camera_zones_plugin(app: &mut App) {
+    app.add_systems(OnEnter(TopDownState::Loading), clear_camera_zones)
+        .init_resource::<CameraZones>()
+        .register_type::<CameraZone>()
+        .register_type::<CameraRule>();
+}
+
+// An area of a Tiled map that changes how the camera follows the player while
+// they're in it, its bounds are the object's collider
+#[derive(Debug, Clone, Default, Component, Reflect)]
+#[reflect(Component, Default)]
+pub struct CameraZone {
+    rule:     CameraRule,
+    // Scale of the camera, 0 keeps the usual one
+    zoom:     f32,
+    // The highest priority wins where zones overlap
+    priority: i32,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Reflect)]
+#[reflect(Default)]
+pub enum CameraRule {
+    #[default]
+    Follow,
+    // Holds the camera's x on the zone's center
+    LockX,
+    // Holds the camera's y on the zone's center
+    LockY,
+    // Holds the camera on the zone's center, e.g. for a single room
+    Fixed,
+    // Follows the player without showing anything outside the zone
+    Clamp,
+}
+
+impl CameraRule {
+    // Where the camera should be looking given where it would follow the player to
+    pub fn target(&self, follow: Vec2, zone: Rect, view_size: Vec2) -> Vec2 {
+        let center = zone.center();
+        match self {
+            CameraRule::Follow => follow,
+            CameraRule::LockX => vec2(center.x, follow.y),
+            CameraRule::LockY => vec2(follow.x, center.y),
+            CameraRule::Fixed => center,
+            CameraRule::Clamp => clamp_view(
+                follow,
+                zone.min + view_size / 2.0,
+                zone.max - view_size / 2.0,
+            ),
+        }
+    }
+}
+
+// Like Vec2::clamp, but centers on an axis where the view doesn't fit
+pub fn clamp_view(position: Vec2, min: Vec2, max: Vec2) -> Vec2 {
+    let clamp_axis = |position: f32, min: f32, max: f32| match min <= max {
+        true => position.clamp(min, max),
+        false => (min + max) / 2.0,
+    };
+    vec2(
+        clamp_axis(position.x, min.x, max.x),
+        clamp_axis(position.y, min.y, max.y),
+    )
+}
+
+// Zones the player is in along with the collider giving their bounds
+#[derive(Debug, Default, Resource)]
+pub struct CameraZones(Vec<(Entity, Entity)>);
+
+impl CameraZones {
+    // Highest priority zone the player is in and its bounds
+    pub fn current<'a>(
+        &self,
+        q_zones: &'a Query<&CameraZone>,
+        q_bounds: &Query<&ColliderAabb>,
+    ) -> Option<(&'a CameraZone, Rect)> {
+        self.0
+            .iter()
+            .filter_map(|(zone, collider)| {
+                let zone = q_zones.get(*zone).ok()?;
+                let bounds = q_bounds.get(*collider).ok()?;
+                Some((zone, Rect::from_corners(bounds.min, bounds.max)))
+            })
+            .max_by_key(|(zone, _)| zone.priority)
+    }
+}
+
+fn clear_camera_zones(mut camera_zones: ResMut<CameraZones>) {
+    camera_zones.0.clear();
+}
+
+pub fn observe_camera_zone(entity_commands: &mut EntityCommands) {
+    entity_commands
+        .insert((Sensor, CollisionEventsEnabled))
+        .observe(camera_zone_enter)
+        .observe(camera_zone_exit);
+}
+
+fn camera_zone_enter(
+    trigger: Trigger<OnCollisionStart>,
+    player: Single<Entity, With<Player>>,
+    q_tiled_colliders: Query<&ChildOf, With<TiledColliderMarker>>,
+    mut camera_zones: ResMut<CameraZones>,
+) {
+    if trigger.collider != *player {
+        return;
+    }
+
+    let collider = trigger.target();
+    if let Ok(ChildOf(zone)) = q_tiled_colliders.get(collider) {
+        if !camera_zones.0.contains(&(*zone, collider)) {
+            camera_zones.0.push((*zone, collider));
+        }
+    }
+}
+
+fn camera_zone_exit(
+    trigger: Trigger<OnCollisionEnd>,
+    player: Single<Entity, With<Player>>,
+    mut camera_zones: ResMut<CameraZones>,
+) {
+    if trigger.collider == *player {
+        let collider = trigger.target();
+        camera_zones
+            .0
+            .retain(|(_, zone_collider)| *zone_collider != collider);
+    }
+}
@@ -0,0 +1,317 @@
+use bevy::{
+    render::{
+        mesh::MeshVertexBufferLayoutRef,
+        render_resource::{
+            AsBindGroup, BlendComponent, BlendFactor, BlendOperation, BlendState,
+            RenderPipelineDescriptor, ShaderRef, SpecializedMeshPipelineError,
+        },
+    },
+    sprite::{AlphaMode2d, Material2d, Material2dKey, Material2dPlugin},
+    transform::TransformSystem,
+};
+
+use super::*;
+
+pub fn topdown_day_night_plugin(app: &mut App) {
+    app.add_plugins(Material2dPlugin::<DayNightMaterial>::default())
+        .add_special_interaction("set_time", set_time_special)
+        .add_special_interaction("clock_rate", clock_rate_special)
+        .add_systems(OnEnter(GameState::TopDown), spawn_day_night_overlay)
+        .add_systems(
+            Update,
+            (
+                advance_clock.run_if(in_state(TopDownState::Ready).and(in_state(MovementEnabled))),
+                update_presence,
+            )
+                .run_if(in_state(GameState::TopDown)),
+        )
+        .add_systems(
+            PostUpdate,
+            update_day_night_overlay
+                .before(TransformSystem::TransformPropagate)
+                .run_if(in_state(GameState::TopDown)),
+        )
+        .init_resource::<DayNightCycle>()
+        .register_type::<DayNightCycle>()
+        .register_type::<NightLight>()
+        .register_type::<Presence>();
+}
+
+// In front of everything on the map, relative to the camera
+const Z_DAY_NIGHT: f32 = 990.0;
+// Overlay size over the view's, so shake and rotation never show its edges
+const OVERSCAN: f32 = 1.25;
+// Nearest lights to the camera that are drawn, must match the shader
+const MAX_NIGHT_LIGHTS: usize = 32;
+
+// Tint multiplied over the map through the day, as (hour, color)
+const DAYLIGHT: [(f32, Srgba); 8] = [
+    (0.0, Srgba::rgb(0.22, 0.27, 0.5)),
+    (5.0, Srgba::rgb(0.22, 0.27, 0.5)),
+    (6.5, Srgba::rgb(0.95, 0.72, 0.68)),
+    (8.0, Srgba::WHITE),
+    (18.0, Srgba::WHITE),
+    (19.5, Srgba::rgb(1.0, 0.66, 0.5)),
+    (21.0, Srgba::rgb(0.22, 0.27, 0.5)),
+    (24.0, Srgba::rgb(0.22, 0.27, 0.5)),
+];
+
+fn daylight(hour: f32) -> LinearRgba {
+    let hour = hour.rem_euclid(24.0);
+    DAYLIGHT.windows(2).find(|keys| hour < keys[1].0).map_or(
+        LinearRgba::from(DAYLIGHT[0].1),
+        |keys| {
+            let ((from_hour, from), (to_hour, to)) = (keys[0], keys[1]);
+            let t = (hour - from_hour) / (to_hour - from_hour);
+            LinearRgba::from(from).mix(&LinearRgba::from(to), t)
+        },
+    )
+}
+
+// How the clock in Progress moves along
+#[derive(Debug, Resource, Reflect)]
+#[reflect(Resource)]
+pub struct DayNightCycle {
+    // In-game hours per real second, 0 stops the clock
+    pub rate:     f32,
+    // Whether the current map is tinted, set from its TopdownMapInfo
+    pub daylight: bool,
+}
+
+impl Default for DayNightCycle {
+    fn default() -> Self {
+        DayNightCycle {
+            // An in-game hour per real minute
+            rate:     1.0 / 60.0,
+            daylight: true,
+        }
+    }
+}
+
+// Lifts the dark around an object at dusk and night, placed as a Tiled property
+#[derive(Debug, Clone, Component, Reflect)]
+#[reflect(Component, Default)]
+pub struct NightLight {
+    // In pixels
+    radius:    f32,
+    color:     Color,
+    intensity: f32,
+}
+
+impl Default for NightLight {
+    fn default() -> Self {
+        NightLight {
+            radius:    48.0,
+            color:     Color::srgb(1.0, 0.8, 0.5),
+            intensity: 1.0,
+        }
+    }
+}
+
+// Only there while its conditions are met, e.g. an NPC out at night or one
+// that leaves once a flag is set. Hidden along with its colliders and interaction
+#[derive(Debug, Clone, Default, Component, Reflect)]
+#[reflect(Component, Default)]
+pub struct Presence {
+    time:          TimeOfDay,
+    // Flag needed to be there, ProgressFlag::None for none
+    required_flag: ProgressFlag,
+    // Require the flag to be missing instead, e.g. someone who moves away
+    flag_absent:   bool,
+}
+
+impl Presence {
+    pub fn present(&self, progress: &Progress) -> bool {
+        let flag_met = self.required_flag == ProgressFlag::None
+            || progress.contains(&self.required_flag) != self.flag_absent;
+        flag_met && self.time.includes(progress.time_of_day())
+    }
+}
+
+#[derive(Asset, TypePath, AsBindGroup, Debug, Clone)]
+pub struct DayNightMaterial {
+    #[uniform(0)]
+    tint:   LinearRgba,
+    // World position and radius of each light, unused ones have no radius
+    #[uniform(1)]
+    lights: [Vec4; MAX_NIGHT_LIGHTS],
+    // Color of each light, alpha is its intensity
+    #[uniform(2)]
+    colors: [Vec4; MAX_NIGHT_LIGHTS],
+}
+
+impl Material2d for DayNightMaterial {
+    fn fragment_shader() -> ShaderRef {
+        "shaders/day_night.wgsl".into()
+    }
+
+    fn alpha_mode(&self) -> AlphaMode2d {
+        AlphaMode2d::Blend
+    }
+
+    // Multiplies what's already drawn by the overlay's color
+    fn specialize(
+        descriptor: &mut RenderPipelineDescriptor,
+        _layout: &MeshVertexBufferLayoutRef,
+        _key: Material2dKey<Self>,
+    ) -> Result<(), SpecializedMeshPipelineError> {
+        let target = descriptor
+            .fragment
+            .as_mut()
+            .and_then(|fragment| fragment.targets.first_mut())
+            .and_then(Option::as_mut);
+        if let Some(target) = target {
+            target.blend = Some(BlendState {
+                color: BlendComponent {
+                    src_factor: BlendFactor::Dst,
+                    dst_factor: BlendFactor::Zero,
+                    operation:  BlendOperation::Add,
+                },
+                alpha: BlendComponent::OVER,
+            });
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Component)]
+struct DayNightOverlay;
+
+fn spawn_day_night_overlay(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<DayNightMaterial>>,
+    progress: Res<Progress>,
+) {
+    let material = DayNightMaterial {
+        tint:   daylight(progress.clock),
+        lights: [Vec4::ZERO; MAX_NIGHT_LIGHTS],
+        colors: [Vec4::ZERO; MAX_NIGHT_LIGHTS],
+    };
+
+    commands.spawn((
+        OnTopDown,
+        DayNightOverlay,
+        Name::new("Day Night Overlay"),
+        Mesh2d(meshes.add(Rectangle::new(
+            WINDOW_WIDTH * OVERSCAN,
+            WINDOW_HEIGHT * OVERSCAN,
+        ))),
+        MeshMaterial2d(materials.add(material)),
+        Transform::default(),
+        RENDER_LAYER_WORLD,
+    ));
+}
+
+fn advance_clock(mut progress: ResMut<Progress>, cycle: Res<DayNightCycle>, time: Res<Time>) {
+    progress.advance_clock(cycle.rate * time.delta_secs());
+}
+
+fn update_day_night_overlay(
+    overlay: Single<
+        (
+            &mut Transform,
+            &mut Visibility,
+            &MeshMaterial2d<DayNightMaterial>,
+        ),
+        With<DayNightOverlay>,
+    >,
+    camera_transform: Single<&Transform, (With<WorldCamera>, Without<DayNightOverlay>)>,
+    q_lights: Query<(&NightLight, &GlobalTransform, &InheritedVisibility)>,
+    mut materials: ResMut<Assets<DayNightMaterial>>,
+    cycle: Res<DayNightCycle>,
+    progress: Res<Progress>,
+) {
+    let (mut transform, mut visibility, material) = overlay.into_inner();
+
+    visibility.set_if_neq(match cycle.daylight {
+        true => Visibility::Inherited,
+        false => Visibility::Hidden,
+    });
+    if !cycle.daylight {
+        return;
+    }
+
+    // Follows the camera's view, whatever it's zoomed to
+    transform.translation = camera_transform
+        .translation
+        .with_z(camera_transform.translation.z + Z_DAY_NIGHT);
+    transform.scale = camera_transform.scale;
+
+    let Some(material) = materials.get_mut(material) else {
+        return;
+    };
+    material.tint = daylight(progress.clock);
+
+    let camera_position = camera_transform.translation.truncate();
+    let mut lights = q_lights
+        .iter()
+        .filter(|(.., visibility)| visibility.get())
+        .map(|(light, transform, _)| (light, transform.translation().truncate()))
+        .collect::<Vec<_>>();
+    lights.sort_by(|(_, a), (_, b)| {
+        a.distance_squared(camera_position)
+            .total_cmp(&b.distance_squared(camera_position))
+    });
+
+    material.lights = [Vec4::ZERO; MAX_NIGHT_LIGHTS];
+    material.colors = [Vec4::ZERO; MAX_NIGHT_LIGHTS];
+    lights
+        .into_iter()
+        .take(MAX_NIGHT_LIGHTS)
+        .enumerate()
+        .for_each(|(index, (light, position))| {
+            material.lights[index] = position.extend(light.radius).extend(0.0);
+            material.colors[index] = light
+                .color
+                .to_linear()
+                .with_alpha(light.intensity)
+                .to_vec4();
+        });
+}
+
+fn update_presence(
+    mut commands: Commands,
+    mut q_present: Query<(&Presence, &mut Visibility)>,
+    q_colliders: Query<(Entity, &ChildOf, Has<ColliderDisabled>), With<TiledColliderMarker>>,
+    progress: Res<Progress>,
+) {
+    q_present.iter_mut().for_each(|(presence, mut visibility)| {
+        visibility.set_if_neq(match presence.present(&progress) {
+            true => Visibility::Inherited,
+            false => Visibility::Hidden,
+        });
+    });
+
+    // Colliders can be created after their object, so they're checked every time
+    q_colliders
+        .iter()
+        .for_each(|(collider, ChildOf(parent), disabled)| {
+            let Ok((presence, _)) = q_present.get(*parent) else {
+                return;
+            };
+            match (presence.present(&progress), disabled) {
+                (true, true) => {
+                    commands.entity(collider).remove::<ColliderDisabled>();
+                }
+                (false, false) => {
+                    commands.entity(collider).insert(ColliderDisabled);
+                }
+                _ => {}
+            }
+        });
+}
+
+fn set_time_special(In(args): In<SpecialArgs>, mut progress: ResMut<Progress>) {
+    match args.first().map(|hour| hour.parse::<f32>()) {
+        Some(Ok(hour)) => progress.clock = hour.rem_euclid(24.0),
+        _ => warn!("set_time needs the hour to set the clock to, e.g. 21.5"),
+    }
+}
+
+fn clock_rate_special(In(args): In<SpecialArgs>, mut cycle: ResMut<DayNightCycle>) {
+    match args.first().map(|rate| rate.parse::<f32>()) {
+        Some(Ok(rate)) => cycle.rate = rate.max(0.0),
+        _ => warn!("clock_rate needs the in-game hours per real second"),
+    }
+}
@@ -0,0 +1,82 @@
+use bevy::audio::Volume;
+
+use super::*;
+
+pub fn topdown_map_audio_plugin(app: &mut App) {
+    app.add_systems(OnEnter(TopDownState::Ready), update_map_audio)
+        .add_systems(
+            OnExit(GameState::TopDown),
+            (audio_fade_out::<Music>, audio_fade_out::<Ambience>),
+        );
+}
+
+// Path of the track a map's Music or Ambience is playing
+#[derive(Debug, Component)]
+struct MapTrack(String);
+
+type MapAudio<'a> = (Entity, Option<&'a MapTrack>);
+
+fn update_map_audio(
+    mut commands: Commands,
+    topdown_maps: Res<TopdownMaps>,
+    progress: Res<Progress>,
+    asset_server: Res<AssetServer>,
+    q_music: Query<MapAudio, (With<Music>, Without<AudioFadeOut>)>,
+    q_ambience: Query<MapAudio, (With<Ambience>, Without<AudioFadeOut>)>,
+) {
+    let Ok(map) = topdown_maps.get(&progress.map) else {
+        return;
+    };
+
+    crossfade_map_track(
+        &mut commands,
+        &asset_server,
+        q_music.iter(),
+        map.info.music.as_deref(),
+        (Music, Name::new("Map Music")),
+    );
+    crossfade_map_track(
+        &mut commands,
+        &asset_server,
+        q_ambience.iter(),
+        map.info.ambience.as_deref(),
+        (Ambience, Name::new("Map Ambience")),
+    );
+}
+
+// Fades out everything playing that isn't the track, then fades the track in
+// unless it was already playing from the previous map
+fn crossfade_map_track<'a>(
+    commands: &mut Commands,
+    asset_server: &AssetServer,
+    playing: impl Iterator<Item = MapAudio<'a>>,
+    track: Option<&str>,
+    bundle: impl Bundle,
+) {
+    let mut already_playing = false;
+
+    playing.for_each(|(entity, map_track)| {
+        match map_track.is_some_and(|MapTrack(path)| Some(path.as_str()) == track) {
+            true => already_playing = true,
+            false => {
+                commands
+                    .entity(entity)
+                    .remove::<AudioFadeIn>()
+                    .insert(AudioFadeOut);
+            }
+        }
+    });
+
+    let Some(path) = track.filter(|_| !already_playing) else {
+        return;
+    };
+
+    info!("Fading in map track {path}");
+    commands.spawn((
+        bundle,
+        MapTrack(path.to_string()),
+        AudioPlayer::new(asset_server.load::<AudioSource>(path)),
+        PlaybackSettings::LOOP.with_volume(Volume::SILENT),
+        AudioFadeIn,
+    ));
+}
@@ -0,0 +1,156 @@
+use bevy::{asset::LoadState, platform::collections::HashMap, prelude::*};
+use bevy_ecs_tiled::prelude::*;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::Blob;
+
+pub fn topdown_maps_plugin(app: &mut App) {
+    app.add_systems(Startup, load_map_manifest)
+        .add_systems(
+            Update,
+            build_topdown_maps.run_if(resource_exists::<TopdownMapManifest>),
+        )
+        .init_resource::<TopdownMaps>()
+        .register_type::<MapKey>();
+}
+
+const MANIFEST_PATH: &str = "maps/maps.ron";
+
+// Stable name of a topdown map in assets/maps/maps.ron, stored in saves
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, Deref, Reflect, Serialize, Deserialize)]
+#[reflect(Default, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct MapKey(pub String);
+
+impl MapKey {
+    // Map a new save slot starts on
+    pub const FIRST: &str = "mountains";
+
+    pub fn first() -> Self {
+        MapKey::from(MapKey::FIRST)
+    }
+}
+
+impl From<&str> for MapKey {
+    fn from(key: &str) -> Self {
+        MapKey(key.to_string())
+    }
+}
+
+impl std::fmt::Display for MapKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+fn default_camera_scale() -> f32 {
+    0.5
+}
+
+fn default_daylight() -> bool {
+    true
+}
+
+// An entry of assets/maps/maps.ron
+#[derive(Debug, Clone, Deserialize)]
+pub struct TopdownMapInfo {
+    pub key:          MapKey,
+    pub path:         String,
+    // Locale key of the name shown to the player
+    pub name:         String,
+    #[serde(default)]
+    pub music:        Option<String>,
+    #[serde(default)]
+    pub ambience:     Option<String>,
+    #[serde(default = "default_camera_scale")]
+    pub camera_scale: f32,
+    // Whether the time of day tints the map, false for indoors
+    #[serde(default = "default_daylight")]
+    pub daylight:     bool,
+}
+
+#[derive(Debug)]
+pub struct TopdownMap {
+    pub info:   TopdownMapInfo,
+    pub handle: Handle<TiledMap>,
+}
+
+#[derive(Debug, Error)]
+pub enum MapLookupError {
+    #[error("Map \"{0}\" is not in {MANIFEST_PATH}")]
+    Unknown(MapKey),
+}
+
+// Every topdown map listed in the manifest, looked up by key
+#[derive(Debug, Default, Resource)]
+pub struct TopdownMaps {
+    maps:   HashMap<MapKey, TopdownMap>,
+    loaded: bool,
+}
+
+impl TopdownMaps {
+    pub fn get(&self, key: &MapKey) -> Result<&TopdownMap, MapLookupError> {
+        self.maps
+            .get(key)
+            .ok_or_else(|| MapLookupError::Unknown(key.clone()))
+    }
+
+    pub fn contains(&self, key: &MapKey) -> bool {
+        self.maps.contains_key(key)
+    }
+
+    pub fn loaded(&self) -> bool {
+        self.loaded
+    }
+}
+
+#[derive(Debug, Resource)]
+struct TopdownMapManifest(Handle<Blob>);
+
+fn load_map_manifest(mut commands: Commands, asset_server: Res<AssetServer>) {
+    commands.insert_resource(TopdownMapManifest(asset_server.load(MANIFEST_PATH)));
+}
+
+fn build_topdown_maps(
+    mut commands: Commands,
+    manifest: Res<TopdownMapManifest>,
+    assets_blob: Res<Assets<Blob>>,
+    asset_server: Res<AssetServer>,
+    mut topdown_maps: ResMut<TopdownMaps>,
+) {
+    use bevy::scene::ron::de::from_bytes;
+
+    let entries: Vec<TopdownMapInfo> = match asset_server.get_load_state(manifest.0.id()) {
+        Some(LoadState::Loaded) => {
+            let Some(blob) = assets_blob.get(&manifest.0) else {
+                return;
+            };
+            from_bytes(&blob.bytes).unwrap_or_else(|error| {
+                error!("Map manifest {MANIFEST_PATH} is invalid: {error}");
+                Vec::new()
+            })
+        }
+        Some(LoadState::Failed(error)) => {
+            error!("Map manifest {MANIFEST_PATH} could not be loaded: {error}");
+            Vec::new()
+        }
+        _ => return,
+    };
+
+    topdown_maps.maps = entries
+        .into_iter()
+        .map(|info| {
+            let handle = asset_server.load(&info.path);
+            (info.key.clone(), TopdownMap { info, handle })
+        })
+        .collect();
+    topdown_maps.loaded = true;
+
+    if !topdown_maps.contains(&MapKey::first()) {
+        error!("First map \"{}\" is not in {MANIFEST_PATH}", MapKey::FIRST);
+    }
+
+    info!("Registered {} topdown maps", topdown_maps.maps.len());
+    commands.remove_resource::<TopdownMapManifest>();
+}
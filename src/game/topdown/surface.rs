@@ -0,0 +1,150 @@
+use super::*;
+
+pub fn topdown_surface_plugin(app: &mut App) {
+    app.add_systems(
+        Update,
+        update_surface_particles.run_if(any_with_component::<SurfaceParticle>),
+    )
+    .add_observer(surface_impact)
+    .register_type::<Surface>()
+    .register_type::<PlayerSurface>();
+}
+
+// What a tile is made of, set as a tile property in Tiled
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Component, Reflect)]
+#[reflect(Default, Component)]
+pub enum Surface {
+    #[default]
+    Grass,
+    Sand,
+    Wood,
+    Stone,
+    Water,
+}
+
+impl Surface {
+    fn particle_color(&self) -> Color {
+        match self {
+            Surface::Grass => Color::srgba(0.45, 0.6, 0.3, 0.8),
+            Surface::Sand => Color::srgba(0.9, 0.8, 0.55, 0.8),
+            Surface::Wood => Color::srgba(0.55, 0.4, 0.25, 0.6),
+            Surface::Stone => Color::srgba(0.7, 0.7, 0.7, 0.6),
+            Surface::Water => Color::srgba(0.8, 0.9, 1.0, 0.9),
+        }
+    }
+
+    // Scales the hop impulse, e.g. sand is harder to hop off of
+    pub fn hop_scale(&self) -> f32 {
+        match self {
+            Surface::Sand => 0.7,
+            Surface::Stone => 1.1,
+            _ => 1.0,
+        }
+    }
+}
+
+// Surface under the player, the top-most tile with a Surface wins
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deref, Component, Reflect)]
+#[reflect(Default, Component)]
+pub struct PlayerSurface(pub Surface);
+
+// Triggered on the player when it lands or swims on its PlayerSurface
+#[derive(Debug, Event)]
+pub struct SurfaceImpact;
+
+pub fn update_player_surface(
+    player: Single<
+        (&Transform, &mut PlayerSurface, &mut Submerged),
+        (With<Player>, Changed<Transform>),
+    >,
+    map_info: Res<MapInfo>,
+    q_tiled_layers: Query<(&TileStorage, &Transform), With<TiledMapTileLayerForTileset>>,
+    q_surface_tiles: Query<(Option<&Surface>, Has<WaterTile>), With<TiledMapTile>>,
+) {
+    let (transform, mut player_surface, mut submerged) = player.into_inner();
+
+    let Some(player_tile_pos) = get_tile_pos(transform.translation, &map_info.tilemap_size) else {
+        debug_once!("Player outside map");
+        return;
+    };
+
+    let surface = q_tiled_layers
+        .iter()
+        .filter_map(|(tile_storage, layer_transform)| {
+            let tile = tile_storage.get(&player_tile_pos)?;
+            let surface = match q_surface_tiles.get(tile).ok()? {
+                (Some(surface), _) => *surface,
+                // Older maps only mark water
+                (None, true) => Surface::Water,
+                (None, false) => return None,
+            };
+            Some((layer_transform.translation.z, surface))
+        })
+        .max_by(|(z_a, _), (z_b, _)| z_a.total_cmp(z_b))
+        .map(|(_, surface)| surface)
+        .unwrap_or_default();
+
+    player_surface.set_if_neq(PlayerSurface(surface));
+    submerged.set_if_neq(Submerged(surface == Surface::Water));
+}
+
+#[derive(Debug, Component)]
+struct SurfaceParticle {
+    velocity: Vec2,
+    alpha:    f32,
+    timer:    Timer,
+}
+
+fn surface_impact(
+    trigger: Trigger<SurfaceImpact>,
+    mut commands: Commands,
+    q_players: Query<(&Transform, &PlayerSurface)>,
+    mut rng: GlobalEntropy<WyRand>,
+) {
+    const PARTICLE_COUNT: usize = 6;
+    const PARTICLE_SPEED: f32 = 24.0;
+    const PARTICLE_LIFETIME: f32 = 0.4;
+    const FOOT_OFFSET: Vec3 = vec3(0.0, -12.0, 0.1);
+
+    let Ok((transform, PlayerSurface(surface))) = q_players.get(trigger.target()) else {
+        return;
+    };
+
+    (0..PARTICLE_COUNT).for_each(|_| {
+        let angle = random_range(&mut rng, 0.0, std::f32::consts::PI);
+        let speed = random_range(&mut rng, 0.5, 1.0) * PARTICLE_SPEED;
+        let color = surface.particle_color();
+        commands.spawn((
+            OnTopDown,
+            Name::new("Surface Particle"),
+            SurfaceParticle {
+                velocity: Vec2::from_angle(angle) * speed,
+                alpha:    color.alpha(),
+                timer:    Timer::from_seconds(PARTICLE_LIFETIME, TimerMode::Once),
+            },
+            Sprite::from_color(color, Vec2::splat(2.0)),
+            Transform::from_translation(transform.translation + FOOT_OFFSET),
+            RENDER_LAYER_WORLD,
+        ));
+    });
+}
+
+fn update_surface_particles(
+    mut commands: Commands,
+    mut q_particles: Query<(Entity, &mut SurfaceParticle, &mut Transform, &mut Sprite)>,
+    time: Res<Time>,
+) {
+    q_particles
+        .iter_mut()
+        .for_each(|(entity, mut particle, mut transform, mut sprite)| {
+            if particle.timer.tick(time.delta()).finished() {
+                commands.entity(entity).despawn();
+                return;
+            }
+
+            transform.translation += (particle.velocity * time.delta_secs()).extend(0.0);
+            sprite
+                .color
+                .set_alpha(particle.alpha * particle.timer.fraction_remaining());
+        });
+}
@@ -0,0 +1,133 @@
+use bevy::audio::Volume;
+
+use super::*;
+
+pub fn topdown_triggers_plugin(app: &mut App) {
+    app.register_type::<TriggerZone>()
+        .register_type::<TriggerAction>();
+}
+
+// An area of a Tiled map that does something when the player walks in or out of it
+#[derive(Debug, Clone, Default, Component, Reflect)]
+#[reflect(Component, Default)]
+pub struct TriggerZone {
+    // Fire when the player leaves the zone instead of entering it
+    on_exit:       bool,
+    // Once-only zones are remembered per save slot
+    repeatable:    bool,
+    // Flag that must be set for the zone to fire, ProgressFlag::None for none
+    required_flag: ProgressFlag,
+    // Time of day the zone fires at, TimeOfDay::Any for always
+    required_time: TimeOfDay,
+    action:        TriggerAction,
+}
+
+#[derive(Debug, Clone, Default, Reflect)]
+#[reflect(Default)]
+pub enum TriggerAction {
+    #[default]
+    None,
+    // Plays like it was interacted with, e.g. a Dialogue or a Special
+    Interaction(EntityInteraction),
+    SetFlag(ProgressFlag),
+    // Path of a sound to play
+    PlaySound(String),
+    // Path of a .camera.ron to play
+    CameraSequence(String),
+}
+
+pub fn observe_trigger_zone(entity_commands: &mut EntityCommands) {
+    entity_commands
+        .insert((Sensor, CollisionEventsEnabled))
+        .observe(trigger_zone_enter)
+        .observe(trigger_zone_exit);
+}
+
+fn trigger_zone_enter(
+    trigger: Trigger<OnCollisionStart>,
+    player: Single<Entity, With<Player>>,
+    q_tiled_colliders: Query<&ChildOf, With<TiledColliderMarker>>,
+    mut commands: Commands,
+) {
+    if trigger.collider == *player {
+        if let Ok(ChildOf(parent)) = q_tiled_colliders.get(trigger.target()) {
+            commands.run_system_cached_with(fire_trigger_zone, (*parent, false));
+        }
+    }
+}
+
+fn trigger_zone_exit(
+    trigger: Trigger<OnCollisionEnd>,
+    player: Single<Entity, With<Player>>,
+    q_tiled_colliders: Query<&ChildOf, With<TiledColliderMarker>>,
+    mut commands: Commands,
+) {
+    if trigger.collider == *player {
+        if let Ok(ChildOf(parent)) = q_tiled_colliders.get(trigger.target()) {
+            commands.run_system_cached_with(fire_trigger_zone, (*parent, true));
+        }
+    }
+}
+
+fn fire_trigger_zone(
+    In((zone_entity, exited)): In<(Entity, bool)>,
+    q_trigger_zones: Query<(&TriggerZone, Option<&InteractionId>)>,
+    interaction_state: Res<State<InteractionState>>,
+    mut progress: ResMut<Progress>,
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    settings: Res<Persistent<Settings>>,
+) {
+    let Ok((zone, zone_id)) = q_trigger_zones.get(zone_entity) else {
+        return;
+    };
+
+    if zone.on_exit != exited {
+        return;
+    }
+
+    if zone.required_flag != ProgressFlag::None && !progress.contains(&zone.required_flag) {
+        return;
+    }
+
+    if !zone.required_time.includes(progress.time_of_day()) {
+        return;
+    }
+
+    let starts_interaction = matches!(zone.action, TriggerAction::Interaction(_));
+    if starts_interaction && *interaction_state.get() != InteractionState::None {
+        // Zone stays armed until the current interaction is over and it's entered again
+        return;
+    }
+
+    if !zone.repeatable {
+        match zone_id {
+            Some(id) if progress.consume_interaction(id.clone()) => return,
+            Some(_) => {}
+            None => warn!("Once-only TriggerZone {zone_entity} has no id, firing anyway"),
+        }
+    }
+
+    debug!("TriggerZone {zone_entity} fired: {:?}", zone.action);
+
+    match &zone.action {
+        TriggerAction::None => {}
+        TriggerAction::Interaction(interaction) => {
+            commands.run_system_cached_with(play_interactions, Some(interaction.clone()));
+        }
+        TriggerAction::SetFlag(flag) => {
+            progress.insert(*flag);
+        }
+        TriggerAction::PlaySound(path) => {
+            commands.spawn((
+                Sound,
+                Name::new("Trigger Zone Sound"),
+                AudioPlayer::new(asset_server.load::<AudioSource>(path)),
+                PlaybackSettings::DESPAWN.with_volume(Sound::volume(&settings)),
+            ));
+        }
+        TriggerAction::CameraSequence(path) => {
+            commands.trigger(PlayCameraSequence(path.clone()));
+        }
+    }
+}
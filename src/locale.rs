@@ -0,0 +1,201 @@
+use bevy::{asset::LoadState, platform::collections::HashMap, prelude::*};
+use bevy_persistent::Persistent;
+use serde::Deserialize;
+
+use crate::{Blob, Settings, StandardFont};
+
+pub fn locale_plugin(app: &mut App) {
+    app.add_systems(Startup, load_locale_files)
+        .add_systems(
+            Update,
+            (
+                request_language.run_if(resource_changed::<Persistent<Settings>>),
+                build_locale.run_if(resource_exists::<LocalePending>),
+                update_localized_text,
+            )
+                .chain(),
+        )
+        .init_resource::<Locale>();
+}
+
+const FALLBACK_LANGUAGE: &str = "en";
+const DEFAULT_FONT_PATH: &str = "Silkscreen.ttf";
+
+// An entry of assets/locale/languages.ron
+#[derive(Debug, Clone, Deserialize)]
+pub struct LanguageInfo {
+    pub code: String,
+    pub name: String,
+    // Font used for scripts Silkscreen doesn't cover
+    pub font: Option<String>,
+}
+
+// Strings of the current language, looked up by key
+#[derive(Debug, Default, Resource)]
+pub struct Locale {
+    language:      String,
+    strings:       HashMap<String, String>,
+    fallback:      HashMap<String, String>,
+    pub languages: Vec<LanguageInfo>,
+    // Font for text that doesn't use the StandardFont, the default font if unset
+    pub text_font: Handle<Font>,
+}
+
+impl Locale {
+    pub fn get<'a>(&'a self, key: &'a str) -> &'a str {
+        if let Some(text) = self.strings.get(key) {
+            return text;
+        }
+
+        match self.fallback.get(key) {
+            Some(text) => {
+                warn!(
+                    "Locale key \"{key}\" missing for \"{}\", falling back to English",
+                    self.language
+                );
+                text
+            }
+            None => {
+                warn!("Locale key \"{key}\" missing");
+                key
+            }
+        }
+    }
+
+    pub fn loaded(&self) -> bool {
+        !self.language.is_empty()
+    }
+}
+
+// Text that is kept up to date with the current language
+#[derive(Debug, Clone, Component)]
+#[require(Text)]
+pub struct LocalizedText(pub String);
+
+impl LocalizedText {
+    pub fn new(key: impl Into<String>) -> Self {
+        LocalizedText(key.into())
+    }
+}
+
+#[derive(Debug, Resource)]
+struct LocaleFiles {
+    manifest: Handle<Blob>,
+    fallback: Handle<Blob>,
+}
+
+#[derive(Debug, Resource)]
+struct LocalePending {
+    language: String,
+    strings:  Handle<Blob>,
+}
+
+fn load_locale_files(mut commands: Commands, asset_server: Res<AssetServer>) {
+    commands.insert_resource(LocaleFiles {
+        manifest: asset_server.load("locale/languages.ron"),
+        fallback: asset_server.load(format!("locale/{FALLBACK_LANGUAGE}.ron")),
+    });
+}
+
+fn request_language(
+    mut commands: Commands,
+    settings: Res<Persistent<Settings>>,
+    locale: Res<Locale>,
+    asset_server: Res<AssetServer>,
+) {
+    if settings.language == locale.language {
+        return;
+    }
+
+    info!("Loading locale: {}", settings.language);
+    commands.insert_resource(LocalePending {
+        language: settings.language.clone(),
+        strings:  asset_server.load(format!("locale/{}.ron", settings.language)),
+    });
+}
+
+fn build_locale(
+    mut commands: Commands,
+    pending: Res<LocalePending>,
+    files: Res<LocaleFiles>,
+    assets_blob: Res<Assets<Blob>>,
+    asset_server: Res<AssetServer>,
+) {
+    // Failed loads are settled too, missing languages fall back to English
+    let settled = |handle: &Handle<Blob>| {
+        matches!(
+            asset_server.get_load_state(handle.id()),
+            Some(LoadState::Loaded | LoadState::Failed(_))
+        )
+    };
+    if ![&files.manifest, &files.fallback, &pending.strings]
+        .into_iter()
+        .all(settled)
+    {
+        return;
+    }
+
+    let languages: Vec<LanguageInfo> = parse_locale_file(&assets_blob, &files.manifest);
+    let fallback = parse_locale_file(&assets_blob, &files.fallback);
+    let strings = parse_locale_file(&assets_blob, &pending.strings);
+
+    let language_info = languages.iter().find(|info| info.code == pending.language);
+    if language_info.is_none() {
+        warn!("Language \"{}\" is not in the manifest", pending.language);
+    }
+
+    let font_path = language_info.and_then(|info| info.font.clone());
+    let text_font = font_path
+        .as_ref()
+        .map(|path| asset_server.load(path))
+        .unwrap_or_default();
+    let standard_font = asset_server.load(font_path.as_deref().unwrap_or(DEFAULT_FONT_PATH));
+
+    commands.insert_resource(StandardFont(standard_font));
+    commands.insert_resource(Locale {
+        language: pending.language.clone(),
+        strings,
+        fallback,
+        languages,
+        text_font,
+    });
+    commands.remove_resource::<LocalePending>();
+}
+
+fn parse_locale_file<T>(assets_blob: &Assets<Blob>, handle: &Handle<Blob>) -> T
+where
+    T: Default + for<'de> Deserialize<'de>,
+{
+    use bevy::scene::ron::de::from_bytes;
+
+    let Some(blob) = assets_blob.get(handle) else {
+        warn!("Locale file {:?} could not be loaded", handle.path());
+        return T::default();
+    };
+
+    from_bytes(&blob.bytes).unwrap_or_else(|error| {
+        warn!("Locale file {:?} is invalid: {error}", handle.path());
+        T::default()
+    })
+}
+
+fn update_localized_text(
+    locale: Res<Locale>,
+    font: Res<StandardFont>,
+    mut q_texts: Query<(Ref<LocalizedText>, &mut Text, Option<&mut TextFont>)>,
+) {
+    if !locale.loaded() {
+        return;
+    }
+
+    let locale_changed = locale.is_changed();
+    q_texts
+        .iter_mut()
+        .filter(|(localized, ..)| locale_changed || localized.is_changed())
+        .for_each(|(localized, mut text, text_font)| {
+            text.0 = locale.get(&localized.0).to_string();
+            if let Some(mut text_font) = text_font {
+                text_font.font = font.clone_weak();
+            }
+        });
+}
@@ -17,6 +17,7 @@ use serde::{Deserialize, Serialize};
 use animation::sprite_animations_plugin;
 use audio::audio_plugin;
 use game::game_plugin;
+use locale::locale_plugin;
 use menu::menu_plugin;
 use progress::progress_plugin;
 use splash::splash_plugin;
@@ -26,6 +27,7 @@ use crate::auto_scaling::ScalePlugin;
 mod animation;
 mod audio;
 mod game;
+mod locale;
 mod menu;
 mod progress;
 mod splash;
@@ -100,6 +102,7 @@ fn main() {
     .add_plugins((
         audio_plugin,
         game_plugin,
+        locale_plugin,
         menu_plugin,
         splash_plugin,
         sprite_animations_plugin,
@@ -163,6 +166,7 @@ impl FromWorld for StandardFont {
 }
 
 #[derive(Debug, Resource, Serialize, Deserialize)]
+#[serde(default)]
 struct Settings {
     up:       KeyCode,
     down:     KeyCode,
@@ -171,9 +175,25 @@ struct Settings {
     jump:     KeyCode,
     swap:     KeyCode,
     interact: KeyCode,
+    backlog:  KeyCode,
+    auto:     KeyCode,
+    skip:     KeyCode,
 
-    sound_vol: f32,
-    music_vol: f32,
+    master_vol:   f32,
+    music_vol:    f32,
+    sound_vol:    f32,
+    ambience_vol: f32,
+    voice_vol:    f32,
+
+    auto_advance:  bool,
+    skip_seen:     bool,
+    reading_delay: f32,
+
+    // Scales camera shake, kicks, zooms and hit-stop, 0 turns them off
+    camera_motion: f32,
+
+    // Code of a language in assets/locale/languages.ron
+    language: String,
 }
 
 impl Default for Settings {
@@ -186,9 +206,23 @@ impl Default for Settings {
             jump:     KeyCode::Space,
             swap:     KeyCode::KeyQ,
             interact: KeyCode::KeyE,
+            backlog:  KeyCode::Tab,
+            auto:     KeyCode::KeyZ,
+            skip:     KeyCode::KeyX,
+
+            master_vol:   1.0,
+            music_vol:    1.0,
+            sound_vol:    1.0,
+            ambience_vol: 1.0,
+            voice_vol:    1.0,
+
+            auto_advance:  false,
+            skip_seen:     false,
+            reading_delay: 1.5,
+
+            camera_motion: 1.0,
 
-            sound_vol: 1.0,
-            music_vol: 1.0,
+            language: "en".to_string(),
         }
     }
 }
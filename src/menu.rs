@@ -2,7 +2,12 @@
 use bevy::{color::palettes::css::*, ecs::spawn::SpawnWith, prelude::*};
 use bevy_persistent::Persistent;
 
-use crate::{AppState, Settings, StandardFont, despawn_screen, game::effects::*, progress::*};
+use crate::{
+    AppState, Settings, StandardFont, despawn_screen,
+    game::effects::*,
+    locale::{Locale, LocalizedText},
+    progress::*,
+};
 
 const TEXT_COLOR: Color = Color::Srgba(WHITE_SMOKE);
 
@@ -43,8 +48,16 @@ enum NavigationAction {
 
 #[derive(Debug, Clone, Copy, Component, PartialEq)]
 enum RadioSetting {
-    Sound,
+    Master,
     Music,
+    Sound,
+    Ambience,
+    Voice,
+    AutoAdvance,
+    SkipSeen,
+    ReadingDelay,
+    CameraMotion,
+    Language,
 }
 
 #[derive(Component, Clone, Copy)]
@@ -67,8 +80,7 @@ pub fn menu_plugin(app: &mut App) {
             (update_radio_buttons, save_settings).run_if(in_state(MenuState::Settings)),
         )
         .add_systems(Update, setup_progress.run_if(in_state(MenuState::Data)))
-        .add_systems(OnEnter(MenuState::Fading), fade_to_black)
-        .add_systems(Update, start_game.run_if(on_event::<FadeIn>));
+        .add_systems(OnEnter(MenuState::Fading), fade_to_game);
 }
 
 fn navigation_action(
@@ -176,7 +188,7 @@ fn setup_main_menu(
                 children![
                     // (ImageNode::new(right_icon), button_icon_node.clone()),
                     (
-                        Text::new("Play"),
+                        LocalizedText::new("menu.play"),
                         button_text_font.clone(),
                         TextColor(TEXT_COLOR),
                     ),
@@ -190,7 +202,7 @@ fn setup_main_menu(
                 children![
                     // (ImageNode::new(wrench_icon), button_icon_node.clone()),
                     (
-                        Text::new("Settings"),
+                        LocalizedText::new("menu.settings"),
                         button_text_font.clone(),
                         TextColor(TEXT_COLOR),
                     ),
@@ -203,7 +215,11 @@ fn setup_main_menu(
                 NavigationAction::Quit,
                 children![
                     // (ImageNode::new(exit_icon), button_icon_node),
-                    (Text::new("Quit"), button_text_font, TextColor(TEXT_COLOR),),
+                    (
+                        LocalizedText::new("menu.quit"),
+                        button_text_font,
+                        TextColor(TEXT_COLOR),
+                    ),
                 ]
             ),
         ],
@@ -218,6 +234,7 @@ fn setup_settings(
     _asset_server: Res<AssetServer>,
     font: Res<StandardFont>,
     settings: Res<Persistent<Settings>>,
+    locale: Res<Locale>,
 ) {
     let settings_root = commands
         .spawn((
@@ -242,9 +259,23 @@ fn setup_settings(
         jump: _jump,
         swap: _swap,
         interact: _interact,
+        backlog: _backlog,
+        auto: _auto,
+        skip: _skip,
 
-        sound_vol,
+        master_vol,
         music_vol,
+        sound_vol,
+        ambience_vol,
+        voice_vol,
+
+        auto_advance,
+        skip_seen,
+        reading_delay,
+
+        camera_motion,
+
+        ref language,
     } = *settings.get();
 
     let settings_node = Node {
@@ -282,7 +313,7 @@ fn setup_settings(
         },
         BackgroundColor(SLATE_GREY.into()),
         children![(
-            Text::new("Key Bindings"),
+            LocalizedText::new("menu.key_bindings"),
             TextFont {
                 font: font.clone_weak(),
                 font_size: 48.0,
@@ -293,86 +324,130 @@ fn setup_settings(
         )],
     ));
 
+    let label_font = TextFont {
+        font: font.clone_weak(),
+        font_size: 32.0,
+        font_smoothing: bevy::text::FontSmoothing::None,
+        ..default()
+    };
+
+    let volume_levels = || (0..=10).map(|level| (level, OptionLabel::Block)).collect();
+    let toggle = || {
+        vec![
+            (0, OptionLabel::Key("menu.off")),
+            (1, OptionLabel::Key("menu.on")),
+        ]
+    };
+
+    [
+        (
+            "Master Volume",
+            "menu.master",
+            RadioSetting::Master,
+            master_vol,
+        ),
+        ("Music Volume", "menu.music", RadioSetting::Music, music_vol),
+        ("Sound Volume", "menu.sound", RadioSetting::Sound, sound_vol),
+        (
+            "Ambience Volume",
+            "menu.ambience",
+            RadioSetting::Ambience,
+            ambience_vol,
+        ),
+        ("Voice Volume", "menu.voice", RadioSetting::Voice, voice_vol),
+    ]
+    .into_iter()
+    .for_each(|(name, label, setting, volume)| {
+        commands.spawn((
+            ChildOf(settings_root),
+            radio_row(
+                name,
+                label,
+                setting,
+                volume_levels(),
+                (volume * 10.0).round() as u32,
+                label_font.clone(),
+            ),
+        ));
+    });
+
     commands.spawn((
-        Name::new("Music Volume"),
         ChildOf(settings_root),
-        settings_node.clone(),
-        BackgroundColor(DARK_GREY.into()),
-        Children::spawn((
-            Spawn((
-                Label,
-                Text::new("Music"),
-                TextFont {
-                    font: font.clone_weak(),
-                    font_size: 32.0,
-                    font_smoothing: bevy::text::FontSmoothing::None,
-                    ..default()
-                },
-                TextColor(TEXT_COLOR),
-            )),
-            SpawnWith(move |parent: &mut ChildSpawner| {
-                (0..=10).for_each(|level| {
-                    let mut entity = parent.spawn((
-                        RadioSetting::Music,
-                        Button,
-                        Node {
-                            width: Val::Px(32.0),
-                            height: Val::Px(48.0),
-                            margin: UiRect::all(Val::Px(8.0)),
-                            ..default()
-                        },
-                        BackgroundColor(NORMAL_BUTTON),
-                        RadioValue(level),
-                    ));
+        radio_row(
+            "Auto Advance",
+            "menu.auto_advance",
+            RadioSetting::AutoAdvance,
+            toggle(),
+            auto_advance as u32,
+            label_font.clone(),
+        ),
+    ));
 
-                    let volume = 0.1 * level as f32;
-                    if music_vol == volume {
-                        entity.insert(SelectedOption);
-                    }
-                });
-            }),
-        )),
+    commands.spawn((
+        ChildOf(settings_root),
+        radio_row(
+            "Skip Seen",
+            "menu.skip_seen",
+            RadioSetting::SkipSeen,
+            toggle(),
+            skip_seen as u32,
+            label_font.clone(),
+        ),
     ));
 
     commands.spawn((
-        Name::new("Sound Volume"),
         ChildOf(settings_root),
-        settings_node.clone(),
-        BackgroundColor(DARK_GREY.into()),
-        Children::spawn((
-            Spawn((
-                Label,
-                Text::new("Sound"),
-                TextFont {
-                    font: font.clone_weak(),
-                    font_size: 32.0,
-                    font_smoothing: bevy::text::FontSmoothing::None,
-                    ..default()
-                },
-                TextColor(TEXT_COLOR),
-            )),
-            SpawnWith(move |parent: &mut ChildSpawner| {
-                (0..=10).for_each(|level| {
-                    let mut entity = parent.spawn((
-                        RadioSetting::Sound,
-                        Button,
-                        Node {
-                            width: Val::Px(32.0),
-                            height: Val::Px(48.0),
-                            margin: UiRect::all(Val::Px(8.0)),
-                            ..default()
-                        },
-                        BackgroundColor(NORMAL_BUTTON),
-                        RadioValue(level),
-                    ));
+        radio_row(
+            "Reading Delay",
+            "menu.reading_delay",
+            RadioSetting::ReadingDelay,
+            (0..=6)
+                .map(|step| {
+                    let seconds = step as f32 * READING_DELAY_STEP;
+                    (step, OptionLabel::Literal(format!("{seconds}s")))
+                })
+                .collect(),
+            (reading_delay / READING_DELAY_STEP).round() as u32,
+            label_font.clone(),
+        ),
+    ));
 
-                    let volume = 0.1 * level as f32;
-                    if sound_vol == volume {
-                        entity.insert(SelectedOption);
-                    }
-                });
-            }),
-        )),
+    commands.spawn((
+        ChildOf(settings_root),
+        radio_row(
+            "Camera Motion",
+            "menu.camera_motion",
+            RadioSetting::CameraMotion,
+            vec![
+                (0, OptionLabel::Key("menu.off")),
+                (1, OptionLabel::Key("menu.reduced")),
+                (2, OptionLabel::Key("menu.full")),
+            ],
+            (camera_motion / CAMERA_MOTION_STEP).round() as u32,
+            label_font.clone(),
+        ),
+    ));
+
+    commands.spawn((
+        ChildOf(settings_root),
+        radio_row(
+            "Language",
+            "menu.language",
+            RadioSetting::Language,
+            locale
+                .languages
+                .iter()
+                .zip(0..)
+                .map(|(info, index)| (index, OptionLabel::Literal(info.name.clone())))
+                .collect(),
+            locale
+                .languages
+                .iter()
+                .zip(0..)
+                .find_map(|(info, index)| (info.code == *language).then_some(index))
+                .unwrap_or_default(),
+            label_font,
+        ),
     ));
 
     commands.spawn((
@@ -389,7 +464,7 @@ fn setup_settings(
                 children![
                     // (ImageNode::new(right_icon), button_icon_node.clone()),
                     (
-                        Text::new("Back"),
+                        LocalizedText::new("menu.back"),
                         button_text_font.clone(),
                         TextColor(TEXT_COLOR),
                     ),
@@ -403,7 +478,7 @@ fn setup_settings(
                 children![
                     // (ImageNode::new(wrench_icon), button_icon_node.clone()),
                     (
-                        Text::new("Save & Exit"),
+                        LocalizedText::new("menu.save_exit"),
                         button_text_font.clone(),
                         TextColor(TEXT_COLOR),
                     ),
@@ -413,6 +488,95 @@ fn setup_settings(
     ));
 }
 
+const READING_DELAY_STEP: f32 = 0.5; // seconds
+const CAMERA_MOTION_STEP: f32 = 0.5;
+
+enum OptionLabel {
+    Block,
+    Key(&'static str),
+    Literal(String),
+}
+
+// A row of mutually exclusive buttons for a setting, the label being a locale key
+fn radio_row(
+    name: &'static str,
+    label: &'static str,
+    setting: RadioSetting,
+    options: Vec<(u32, OptionLabel)>,
+    selected: u32,
+    font: TextFont,
+) -> impl Bundle {
+    (
+        Name::new(name),
+        Node {
+            width: Val::Percent(80.0),
+            flex_direction: FlexDirection::Row,
+            align_items: AlignItems::Center,
+            justify_content: JustifyContent::Center,
+            ..default()
+        },
+        BackgroundColor(DARK_GREY.into()),
+        Children::spawn((
+            Spawn((
+                Label,
+                LocalizedText::new(label),
+                font.clone(),
+                TextColor(TEXT_COLOR),
+            )),
+            SpawnWith(move |parent: &mut ChildSpawner| {
+                options.into_iter().for_each(|(value, option_label)| {
+                    let width = match option_label {
+                        OptionLabel::Block => Val::Px(32.0),
+                        _ => Val::Auto,
+                    };
+
+                    let mut entity = parent.spawn((
+                        setting,
+                        Button,
+                        Node {
+                            width,
+                            height: Val::Px(48.0),
+                            margin: UiRect::all(Val::Px(8.0)),
+                            padding: UiRect::horizontal(Val::Px(8.0)),
+                            justify_content: JustifyContent::Center,
+                            align_items: AlignItems::Center,
+                            ..default()
+                        },
+                        BackgroundColor(NORMAL_BUTTON),
+                        RadioValue(value),
+                    ));
+
+                    let option_font = TextFont {
+                        font_size: 24.0,
+                        ..font.clone()
+                    };
+                    match option_label {
+                        OptionLabel::Block => {}
+                        OptionLabel::Key(key) => {
+                            entity.with_child((
+                                LocalizedText::new(key),
+                                option_font,
+                                TextColor(TEXT_COLOR),
+                            ));
+                        }
+                        OptionLabel::Literal(text) => {
+                            entity.with_child((
+                                Text::new(text),
+                                option_font,
+                                TextColor(TEXT_COLOR),
+                            ));
+                        }
+                    }
+
+                    if value == selected {
+                        entity.insert(SelectedOption);
+                    }
+                });
+            }),
+        )),
+    )
+}
+
 #[derive(Debug, Component)]
 struct TimePlayed;
 
@@ -423,6 +587,7 @@ fn setup_data_menu(
     mut commands: Commands,
     progress_storage: Res<Persistent<ProgressStorage>>,
     font: Res<StandardFont>,
+    locale: Res<Locale>,
 ) {
     let button_text_font = TextFont {
         font: font.clone_weak(),
@@ -475,7 +640,7 @@ fn setup_data_menu(
                     let hours = minutes / 60;
                     format!("{:02}:{:02}:{:02}", hours, minutes % 60, seconds % 60)
                 })
-                .unwrap_or("Empty".to_string());
+                .unwrap_or(locale.get("menu.slot_empty").to_string());
 
             commands.spawn((
                 ChildOf(slot_selection),
@@ -489,7 +654,7 @@ fn setup_data_menu(
                 BackgroundColor(DARK_GREY.into()),
                 children![
                     (
-                        Text::new(slot.to_string()),
+                        LocalizedText::new(slot.name_key()),
                         button_text_font.clone(),
                         TextColor(TEXT_COLOR),
                     ),
@@ -513,7 +678,7 @@ fn setup_data_menu(
                             ..default()
                         },
                         children![(
-                            Text::new("Play"),
+                            LocalizedText::new("menu.play"),
                             button_text_font.clone(),
                             TextColor(TEXT_COLOR),
                         )]
@@ -548,7 +713,7 @@ fn setup_data_menu(
             children![
                 // (ImageNode::new(right_icon), button_icon_node.clone()),
                 (
-                    Text::new("Back"),
+                    LocalizedText::new("menu.back"),
                     button_text_font.clone(),
                     TextColor(TEXT_COLOR),
                 ),
@@ -577,6 +742,12 @@ fn setup_progress(
     }
 }
 
+fn fade_to_game(mut commands: Commands) {
+    commands
+        .transition(Transition::cover(TransitionKind::Fade, BLACK))
+        .observe(after_transition(start_game));
+}
+
 fn start_game(mut app_state: ResMut<NextState<AppState>>) {
     app_state.set(AppState::Game {
         paused:   false,
@@ -637,6 +808,7 @@ fn save_settings(
     q_radio_settings: Query<(&RadioSetting, &RadioValue), With<SelectedOption>>,
     mut settings: ResMut<Persistent<Settings>>,
     mut menu_state: ResMut<NextState<MenuState>>,
+    locale: Res<Locale>,
 ) {
     if !matches!(save_button.into_inner(), Interaction::Pressed) {
         return;
@@ -645,8 +817,24 @@ fn save_settings(
     q_radio_settings
         .into_iter()
         .for_each(|(setting, RadioValue(value))| match setting {
-            RadioSetting::Sound => settings.sound_vol = 0.1 * *value as f32,
+            RadioSetting::Master => settings.master_vol = 0.1 * *value as f32,
             RadioSetting::Music => settings.music_vol = 0.1 * *value as f32,
+            RadioSetting::Sound => settings.sound_vol = 0.1 * *value as f32,
+            RadioSetting::Ambience => settings.ambience_vol = 0.1 * *value as f32,
+            RadioSetting::Voice => settings.voice_vol = 0.1 * *value as f32,
+            RadioSetting::AutoAdvance => settings.auto_advance = *value == 1,
+            RadioSetting::SkipSeen => settings.skip_seen = *value == 1,
+            RadioSetting::ReadingDelay => {
+                settings.reading_delay = READING_DELAY_STEP * *value as f32
+            }
+            RadioSetting::CameraMotion => {
+                settings.camera_motion = CAMERA_MOTION_STEP * *value as f32
+            }
+            RadioSetting::Language => {
+                if let Some(info) = locale.languages.get(*value as usize) {
+                    settings.language = info.code.clone();
+                }
+            }
         });
 
     settings.persist().expect("Settings should be loaded");
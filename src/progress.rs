@@ -6,31 +6,119 @@ use std::{
     time::Duration,
 };
 
-use crate::{EnumSet, game::topdown::TopdownMapIndex};
+use crate::{
+    EnumMap, EnumSet,
+    game::{
+        interactions::{InteractionId, backlog::Backlog, dialogue::DialogueId},
+        topdown::maps::MapKey,
+    },
+};
 
 pub fn progress_plugin(app: &mut App) {
     app.add_systems(Startup, initialize_saves)
         .register_type::<Progress>()
         .register_type::<ProgressFlag>()
-        .register_type::<ProgressStorage>();
+        .register_type::<ProgressStorage>()
+        .register_type::<TimeOfDay>();
 }
 
 fn initialize_saves(mut commands: Commands) {
     let config_dir = dirs::config_dir()
         .map(|native_config_dir| native_config_dir.join("monkebucko"))
         .unwrap_or(std::path::Path::new("local").to_path_buf());
+    let saves_path = config_dir.join("saves.ron");
+
+    let mut default = ProgressStorage::default();
+    if let Some(legacy) = read_legacy_saves(&saves_path) {
+        // Moved aside so the migrated saves are written in their place
+        let backup = saves_path.with_extension("ron.bak");
+        match std::fs::rename(&saves_path, &backup) {
+            Ok(()) => {
+                info!(
+                    "Migrated saves from before map keys, kept the old ones at {}",
+                    backup.display()
+                );
+                default = legacy.into();
+            }
+            Err(error) => error!("Failed to migrate saves: {error}"),
+        }
+    }
 
     commands.insert_resource(
         Persistent::<ProgressStorage>::builder()
             .name("saves")
             .format(StorageFormat::Ron)
-            .path(config_dir.join("saves.ron"))
-            .default(ProgressStorage::default())
+            .path(saves_path)
+            .default(default)
             .build()
             .expect("failed to initialize saves"),
     )
 }
 
+// Saves that only parse as they were before maps.ron, None for current or missing ones
+fn read_legacy_saves(saves_path: &std::path::Path) -> Option<LegacyProgressStorage> {
+    use bevy::scene::ron::de::from_str;
+
+    let saves = std::fs::read_to_string(saves_path).ok()?;
+    match from_str::<ProgressStorage>(&saves) {
+        Ok(_) => None,
+        Err(_) => from_str::<LegacyProgressStorage>(&saves).ok(),
+    }
+}
+
+// Saves from before maps.ron, when maps were variants of an enum, e.g. `map: Mountain`
+#[derive(Deserialize)]
+struct LegacyProgressStorage([Option<LegacyProgress>; 3]);
+
+#[derive(Deserialize)]
+struct LegacyProgress {
+    time_played: Duration,
+    flags:       EnumSet<ProgressFlag>,
+    map:         LegacyMap,
+    position:    Vec2,
+}
+
+#[derive(Deserialize)]
+enum LegacyMap {
+    Mountain,
+    Backyard,
+    Fields,
+    Forest,
+    Buckotown,
+    Farm,
+    Beach,
+}
+
+impl LegacyMap {
+    fn key(&self) -> MapKey {
+        MapKey::from(match self {
+            LegacyMap::Mountain => "mountains",
+            LegacyMap::Backyard => "backyard",
+            LegacyMap::Fields => "fields",
+            LegacyMap::Forest => "forest",
+            LegacyMap::Buckotown => "buckotown",
+            LegacyMap::Farm => "farm",
+            LegacyMap::Beach => "beach",
+        })
+    }
+}
+
+impl From<LegacyProgressStorage> for ProgressStorage {
+    fn from(LegacyProgressStorage(slots): LegacyProgressStorage) -> Self {
+        ProgressStorage(slots.map(|slot| {
+            slot.map(|legacy| Progress {
+                time_played: legacy.time_played,
+                flags: legacy.flags,
+                map: legacy.map.key(),
+                position: legacy.position,
+                // Keeps the player where they were saved
+                spawn: None,
+                ..default()
+            })
+        }))
+    }
+}
+
 pub fn save_progress_to_disk(
     save_slot: Res<SaveSlot>,
     mut time_played_start: ResMut<TimePlayedStart>,
@@ -65,9 +153,13 @@ pub enum SaveSlot {
     SlotC,
 }
 
-impl std::fmt::Display for SaveSlot {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{:?}", self)
+impl SaveSlot {
+    pub fn name_key(&self) -> &'static str {
+        match self {
+            SaveSlot::SlotA => "menu.slot_a",
+            SaveSlot::SlotB => "menu.slot_b",
+            SaveSlot::SlotC => "menu.slot_c",
+        }
     }
 }
 
@@ -95,24 +187,82 @@ pub struct Progress {
     #[deref]
     pub flags: EnumSet<ProgressFlag>,
 
-    pub map:      TopdownMapIndex,
+    pub map:      MapKey,
     pub position: Vec2,
+    // SpawnPoint on the map to put the player at, replaces position once the map is created
+    #[serde(default)]
+    pub spawn:    Option<String>,
+    // Hour of the in-game day, from 0 up to 24
+    #[serde(default = "start_hour")]
+    pub clock:    f32,
+
+    #[serde(default)]
+    pub backlog:               Backlog,
+    // Amount of lines seen per dialogue, used to skip through seen lines
+    #[serde(default)]
+    pub dialogue_lines_seen:   EnumMap<DialogueId, usize>,
+    // One-shot interactions that have already been played
+    #[serde(default)]
+    pub interactions_consumed: bevy::platform::collections::HashSet<InteractionId>,
+}
+
+fn start_hour() -> f32 {
+    9.0
 }
 
 impl Default for Progress {
     fn default() -> Self {
-        const FIRST_SPAWN: Vec2 = vec2(832.0, 1024.0);
+        const FIRST_SPAWN: &str = "first_spawn";
         Progress {
             time_played: Duration::default(),
 
             flags: EnumSet::default(),
 
-            map:      TopdownMapIndex::default(),
-            position: FIRST_SPAWN,
+            map:      MapKey::first(),
+            position: Vec2::ZERO,
+            spawn:    Some(FIRST_SPAWN.to_string()),
+            clock:    start_hour(),
+
+            backlog:               Backlog::default(),
+            dialogue_lines_seen:   EnumMap::default(),
+            interactions_consumed: Default::default(),
         }
     }
 }
 
+impl Progress {
+    // Marks a dialogue line as seen, returns whether it had been seen before
+    pub fn see_dialogue_line(&mut self, id: DialogueId, line: usize) -> bool {
+        let lines_seen = self.dialogue_lines_seen.entry(id).or_default();
+        let seen = line < *lines_seen;
+        *lines_seen = (*lines_seen).max(line + 1);
+        seen
+    }
+
+    // Marks a one-shot interaction as played, returns whether it was played before
+    pub fn consume_interaction(&mut self, id: InteractionId) -> bool {
+        !self.interactions_consumed.insert(id)
+    }
+
+    pub fn interaction_consumed(&self, id: &InteractionId) -> bool {
+        self.interactions_consumed.contains(id)
+    }
+
+    // Puts the player at a SpawnPoint the next time a topdown map is created
+    pub fn set_spawn(&mut self, map: impl Into<MapKey>, spawn: impl Into<String>) {
+        self.map = map.into();
+        self.spawn = Some(spawn.into());
+    }
+
+    pub fn time_of_day(&self) -> TimeOfDay {
+        TimeOfDay::at(self.clock)
+    }
+
+    pub fn advance_clock(&mut self, hours: f32) {
+        self.clock = (self.clock + hours).rem_euclid(24.0);
+    }
+}
+
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Reflect, Serialize, Deserialize)]
 #[reflect(Serialize, Deserialize)]
 pub enum ProgressFlag {
@@ -129,3 +279,30 @@ impl Hash for ProgressFlag {
 }
 
 impl nohash_hasher::IsEnabled for ProgressFlag {}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Reflect, Serialize, Deserialize)]
+#[reflect(Default, Serialize, Deserialize)]
+pub enum TimeOfDay {
+    // Matches every time of day, like ProgressFlag::None
+    #[default]
+    Any,
+    Dawn,
+    Day,
+    Dusk,
+    Night,
+}
+
+impl TimeOfDay {
+    pub fn at(hour: f32) -> Self {
+        match hour.rem_euclid(24.0) {
+            5.0..8.0 => TimeOfDay::Dawn,
+            8.0..18.0 => TimeOfDay::Day,
+            18.0..21.0 => TimeOfDay::Dusk,
+            _ => TimeOfDay::Night,
+        }
+    }
+
+    pub fn includes(self, time: TimeOfDay) -> bool {
+        self == TimeOfDay::Any || self == time
+    }
+}
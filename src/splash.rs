@@ -1,6 +1,6 @@
 use bevy::prelude::*;
 
-use super::{AppState, despawn_screen};
+use super::{AppState, despawn_screen, game::topdown::maps::TopdownMaps, locale::Locale};
 
 // This plugin will display a splash screen with Bevy logo for 1 second before switching to the menu
 pub fn splash_plugin(app: &mut App) {
@@ -47,13 +47,15 @@ fn splash_setup(mut commands: Commands, asset_server: Res<AssetServer>) {
     commands.insert_resource(SplashTimer(Timer::from_seconds(1.0, TimerMode::Once)));
 }
 
-// Tick the timer, and change state when finished
+// Tick the timer, and change state when finished and the language is loaded
 fn countdown(
     mut game_state: ResMut<NextState<AppState>>,
     time: Res<Time>,
     mut timer: ResMut<SplashTimer>,
+    locale: Res<Locale>,
+    topdown_maps: Res<TopdownMaps>,
 ) {
-    if timer.tick(time.delta()).finished() {
+    if timer.tick(time.delta()).finished() && locale.loaded() && topdown_maps.loaded() {
         game_state.set(AppState::Menu);
     }
 }